@@ -2,73 +2,16 @@
 set -e
 echo "Running $1, $2, $3, $4, $5"
 
-# Get program directory name as $1 and append "-$2" to it if $1 == "tendermint"
-if [ "$1" = "tendermint" ] || [ "$1" = "reth" ]; then
-    program_directory="${1}-$2"
-else
-    program_directory="$1"
-fi
-# If program_directory starts with loop, then set it to loop
-if [[ $program_directory == loop* ]]; then
-    program_directory="loop"
-fi
-if [[ $program_directory == fibonacci* ]]; then
-    program_directory="fibonacci"
-fi
-if [[ $program_directory == sha256* ]]; then
-    program_directory="sha256-$2"
-fi
-if [[ $program_directory == keccak256* ]]; then
-    program_directory="keccak256-$2"
-fi
-if [[ $program_directory == rsp* ]]; then
-    program_directory="rsp-$2"
-fi
-if [[ $program_directory == eddsa-verify* ]]; then
-    program_directory="eddsa-verify-$2"
-fi
-if [[ $program_directory == ecdsa-verify* ]]; then
-    program_directory="ecdsa-verify-$2"
-fi
-if [[ $program_directory == helios* ]]; then
-    program_directory="helios-$2"
-fi
-if [[ $program_directory == groth16-proof-verify* ]]; then
-    program_directory="groth-$2"
-fi
-if [[ $program_directory == zk-email* ]]; then
-    program_directory="zk-email-$2"
-fi
+# RISC Zero guest accelerators. These are read both by the guest build and by the eval binary so
+# that the report records what the guest was built with.
+export RISC0_FEATURE_bigint2=${RISC0_FEATURE_bigint2:-1}
+export RISC0_FEATURE_keccak=${RISC0_FEATURE_keccak:-0}
 
-echo "Building program"
+# Whether to build the guest with its accelerated crates (on), without them (off), or both (ab).
+export PRECOMPILES=${PRECOMPILES:-on}
 
-# cd to program directory computed above
-cd "programs/$program_directory"
-
-# If the prover is sp1, then build the program.
-if [ "$2" == "sp1" ]; then
-    # The reason we don't just use `cargo prove build` from the SP1 CLI is we need to pass a --features ...
-    # flag to select between sp1 and risc0.
-    RUSTFLAGS="-C passes=lower-atomic -C link-arg=-Ttext=0x00200800 -C panic=abort" \
-        RUSTUP_TOOLCHAIN=succinct \
-        CARGO_BUILD_TARGET=riscv32im-succinct-zkvm-elf \
-        cargo build --release --ignore-rust-version --features $2
-fi
-# If the prover is risc0, then build the program.
-if [ "$2" == "risc0" ]; then
-    echo "Building Risc0"
-    CC=gcc CC_riscv32im_risc0_zkvm_elf=~/.risc0/cpp/bin/riscv32-unknown-elf-gcc  RUSTFLAGS="-C passes=loweratomic -C link-arg=-Ttext=0x00200800 -C panic=abort" RISC0_FEATURE_bigint2=1 cargo +risc0 build --release --locked --target riscv32im-risc0-zkvm-elf --manifest-path Cargo.toml --features risc0 
-fi
-
-cd ../../
-
-# If buildOnly flag is set, exit here
-if [ "$6" == "buildOnly" ]; then
-    echo "Build completed. Exiting due to buildOnly flag."
-    exit 0
-fi
-
-echo "Running eval script"
+# The compiler settings of the guest build, GUEST_OPT_LEVEL, GUEST_LTO, GUEST_CODEGEN_UNITS and
+# GUEST_PANIC, are read from the environment by both the guest build and the eval binary.
 
 # Detect whether we're on an instance with a GPU.
 if nvidia-smi > /dev/null 2>&1; then
@@ -104,6 +47,30 @@ if [ "$2" == "risc0" ]; then
  fi
 fi
 
+echo "Building program"
+
+# Build the guest of the program, along with its unpatched build if PRECOMPILES asks for it,
+# unless ELF_SOURCE selects a checked-in ELF or one given by ELF_PATH.
+if [ "${ELF_SOURCE:-built}" == "built" ]; then
+  cargo run \
+      -p sp1-benchmarks-eval \
+      --release \
+      --no-default-features \
+      --features "$FEATURES" \
+      -- \
+      build \
+      --program "$1" \
+      --prover "$2"
+fi
+
+# If buildOnly flag is set, exit here
+if [ "$6" == "buildOnly" ]; then
+    echo "Build completed. Exiting due to buildOnly flag."
+    exit 0
+fi
+
+echo "Running eval script"
+
 if [ $TRACE_FILE ]; then
     echo "Setting TRACE_FILE=$TRACE_FILE"
     export TRACE_FILE=$TRACE_FILE
@@ -120,6 +87,7 @@ RISC0_INFO=1 RUST_LOG=info CUDA_VISIBLE_DEVICES=0 SP1_DISABLE_PROGRAM_CACHE=true
     --no-default-features \
     --features "$FEATURES" \
     -- \
+    run \
     --program "$1" \
     --prover "$2" \
     --hashfn "$3" \
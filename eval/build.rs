@@ -1,3 +1,25 @@
+use std::{env, fs, process::Command};
+
 fn main() {
     vergen::EmitBuilder::builder().build_timestamp().git_sha(true).emit().unwrap();
+
+    // The rustc that builds the eval binary, recorded in every report.
+    let rustc = env::var("RUSTC").unwrap_or("rustc".to_string());
+    let output = Command::new(rustc).arg("--version").output().unwrap();
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    println!("cargo:rustc-env=EVAL_RUSTC_VERSION={}", version);
+
+    // The resolved versions of the prover crates, from the workspace lockfile.
+    let lockfile = fs::read_to_string("../Cargo.lock").unwrap_or_default();
+    for (name, key) in [("sp1-sdk", "EVAL_SP1_VERSION"), ("risc0-zkvm", "EVAL_RISC0_VERSION")] {
+        println!("cargo:rustc-env={}={}", key, locked_version(&lockfile, name).unwrap_or_default());
+    }
+    println!("cargo:rerun-if-changed=../Cargo.lock");
+}
+
+/// Returns the version of the package with the given name in a lockfile.
+fn locked_version<'a>(lockfile: &'a str, name: &str) -> Option<&'a str> {
+    let mut lines = lockfile.lines();
+    lines.find(|line| *line == format!("name = \"{}\"", name))?;
+    lines.next()?.strip_prefix("version = \"")?.strip_suffix('"')
 }
@@ -0,0 +1,396 @@
+use std::{
+    env,
+    fmt::{Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use clap::Args;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    utils::{aggregation_inner_guest, aggregation_size, program_elf_path},
+    Guest, GuestLto, GuestOptLevel, GuestPanic, Precompiles, ProgramId, ProverId,
+};
+
+/// The arguments of the `build` subcommand.
+#[derive(Args, Clone)]
+pub struct BuildArgs {
+    /// The programs whose guests to build.
+    #[arg(long, required = true)]
+    program: Vec<ProgramId>,
+    #[arg(long)]
+    prover: ProverId,
+    /// Whether to build the guests with their accelerated crates, the unpatched builds without
+    /// them, or both.
+    #[arg(long, env = "PRECOMPILES", value_enum, default_value_t = Precompiles::On)]
+    precompiles: Precompiles,
+    /// Rebuild the guests even if an ELF built from the same sources is cached.
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    profile: GuestProfile,
+}
+
+/// The compiler settings the guests are built with, on top of the target's RUSTFLAGS.
+#[derive(Args, Clone)]
+pub struct GuestProfile {
+    /// The optimization level of the guest.
+    #[arg(long = "guest-opt-level", env = "GUEST_OPT_LEVEL", value_enum, default_value_t = GuestOptLevel::O3)]
+    pub opt_level: GuestOptLevel,
+    /// The link-time optimization of the guest. Defaults to the one in the guest's manifest.
+    #[arg(long = "guest-lto", env = "GUEST_LTO", value_enum)]
+    pub lto: Option<GuestLto>,
+    /// The number of codegen units of the guest.
+    #[arg(long = "guest-codegen-units", env = "GUEST_CODEGEN_UNITS", default_value_t = 16)]
+    pub codegen_units: u32,
+    /// The panic strategy of the guest.
+    #[arg(long = "guest-panic", env = "GUEST_PANIC", value_enum, default_value_t = GuestPanic::Abort)]
+    pub panic: GuestPanic,
+}
+
+impl GuestProfile {
+    /// Returns the environment variables that override the release profile of the guest.
+    fn cargo_env(&self) -> Vec<(&'static str, String)> {
+        let mut vars = vec![
+            ("CARGO_PROFILE_RELEASE_OPT_LEVEL", self.opt_level.to_string()),
+            ("CARGO_PROFILE_RELEASE_CODEGEN_UNITS", self.codegen_units.to_string()),
+        ];
+        if let Some(lto) = &self.lto {
+            vars.push(("CARGO_PROFILE_RELEASE_LTO", lto.to_string()));
+        }
+        vars
+    }
+}
+
+/// An error that stopped a guest from being built.
+#[derive(Debug)]
+pub enum BuildError {
+    /// The toolchain of the prover is not installed.
+    MissingToolchain {
+        toolchain: &'static str,
+        install: &'static str,
+    },
+    /// The unpatched build of a guest without accelerated crates was requested.
+    NothingToUnpatch(String),
+    /// Cargo failed to build the guest.
+    Cargo {
+        guest: String,
+        status: ExitStatus,
+    },
+    Io(io::Error),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::MissingToolchain { toolchain, install } => {
+                write!(
+                    f,
+                    "the {} toolchain is not installed, install it with `{}`",
+                    toolchain, install
+                )
+            }
+            BuildError::NothingToUnpatch(guest) => {
+                write!(f, "programs/{} has no accelerated crates to leave out", guest)
+            }
+            BuildError::Cargo { guest, status } => {
+                write!(f, "building programs/{} failed ({})", guest, status)
+            }
+            BuildError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<io::Error> for BuildError {
+    fn from(err: io::Error) -> Self {
+        BuildError::Io(err)
+    }
+}
+
+/// How guests are built for a prover.
+struct Toolchain {
+    /// The rustup toolchain that targets the zkVM.
+    name: &'static str,
+    /// The command that installs the toolchain.
+    install: &'static str,
+    target: &'static str,
+    /// The RUSTFLAGS of the target, which `GuestProfile` adds the panic strategy to.
+    rustflags: &'static str,
+}
+
+impl Toolchain {
+    fn new(prover: &ProverId) -> Self {
+        match prover {
+            ProverId::SP1 => Toolchain {
+                name: "succinct",
+                install: "curl -L https://sp1.succinct.xyz | bash && sp1up",
+                target: "riscv32im-succinct-zkvm-elf",
+                rustflags: "-C passes=lower-atomic -C link-arg=-Ttext=0x00200800",
+            },
+            ProverId::Risc0 => Toolchain {
+                name: "risc0",
+                install: "curl -L https://risczero.com/install | bash && rzup install",
+                target: "riscv32im-risc0-zkvm-elf",
+                rustflags: "-C passes=loweratomic -C link-arg=-Ttext=0x00200800",
+            },
+        }
+    }
+
+    fn missing(&self) -> BuildError {
+        BuildError::MissingToolchain { toolchain: self.name, install: self.install }
+    }
+
+    /// Returns the `rustc` version of the toolchain, or an error if it is not installed.
+    fn version(&self) -> Result<String, BuildError> {
+        let output = Command::new("rustc")
+            .arg(format!("+{}", self.name))
+            .arg("--version")
+            .output()
+            .map_err(|_| self.missing())?;
+        if !output.status.success() {
+            return Err(self.missing());
+        }
+        if self.name == "risc0" && !risc0_cc().exists() {
+            return Err(self.missing());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+/// The C compiler that RISC Zero guests with C dependencies are built with.
+fn risc0_cc() -> PathBuf {
+    let home = env::var("HOME").unwrap_or_default();
+    Path::new(&home).join(".risc0/cpp/bin/riscv32-unknown-elf-gcc")
+}
+
+/// Builds the guests of the given programs, reusing the cached ELF of a guest if its sources and
+/// build settings are unchanged.
+pub fn build(args: &BuildArgs) -> Result<(), BuildError> {
+    let toolchain = Toolchain::new(&args.prover);
+    let version = toolchain.version()?;
+
+    // The guests to build, and whether to build them unpatched.
+    let mut builds = Vec::new();
+    for program in &args.program {
+        let guest = program.guest(&args.prover);
+        // The baseline programs only prove the unpatched build.
+        let precompiles =
+            if program.is_baseline() { Precompiles::Off } else { args.precompiles.clone() };
+        if precompiles != Precompiles::Off {
+            builds.push((guest.clone(), false));
+        }
+        if precompiles != Precompiles::On {
+            builds.push((guest, true));
+        }
+        // The aggregation programs verify proofs of fibonacci, which is always built accelerated.
+        if aggregation_size(program).is_some() {
+            builds.push((aggregation_inner_guest(&args.prover), false));
+        }
+    }
+    builds.sort();
+    builds.dedup();
+
+    for (guest, unpatched) in builds {
+        build_guest(
+            &guest,
+            &args.prover,
+            &toolchain,
+            &version,
+            &args.profile,
+            unpatched,
+            args.force,
+        )?;
+    }
+    Ok(())
+}
+
+/// Builds the guest, or its unpatched build, to the path that `program_elf_path` returns. The guest
+/// is either a crate of its own or the manifest of one prover in `programs/<workload>`, whose shared
+/// sources it points at.
+///
+/// The unpatched build is a copy of the guest in `target/unpatched/crate` without its
+/// `[patch.crates-io]` section, built with its `baseline` feature if it has one.
+fn build_guest(
+    guest: &Guest,
+    prover: &ProverId,
+    toolchain: &Toolchain,
+    version: &str,
+    profile: &GuestProfile,
+    unpatched: bool,
+    force: bool,
+) -> Result<(), BuildError> {
+    let crate_dir = PathBuf::from("programs").join(&guest.dir);
+    let manifest = fs::read_to_string(crate_dir.join("Cargo.toml"))?;
+
+    let mut features = vec![prover.to_string()];
+    if unpatched {
+        let patched = manifest.lines().any(|line| line.trim() == "[patch.crates-io]");
+        let baseline = manifest.lines().any(|line| line.starts_with("baseline = "));
+        if !patched && !baseline {
+            return Err(BuildError::NothingToUnpatch(guest.dir.clone()));
+        }
+        if baseline {
+            features.push("baseline".to_string());
+        }
+    }
+    let features = features.join(" ");
+    let locked = *prover == ProverId::Risc0 && !unpatched;
+
+    let rustflags = format!("{} -C panic={}", toolchain.rustflags, profile.panic);
+    let profile_env = profile.cargo_env();
+
+    // Everything besides the sources that changes the ELF, including the RISC Zero accelerators
+    // that `risc0-zkvm-platform` reads from the environment.
+    let mut settings =
+        format!("{}\n{}\n{}\n{}\n{}\n", version, rustflags, features, unpatched, locked);
+    for (key, value) in &profile_env {
+        settings += &format!("{}={}\n", key, value);
+    }
+    if *prover == ProverId::Risc0 {
+        let mut accelerators =
+            env::vars().filter(|(key, _)| key.starts_with("RISC0_FEATURE_")).collect::<Vec<_>>();
+        accelerators.sort();
+        for (key, value) in accelerators {
+            settings += &format!("{}={}\n", key, value);
+        }
+    }
+
+    let elf_path = PathBuf::from(program_elf_path(guest, prover, unpatched));
+    let hash = source_hash(&crate_dir, &manifest, &settings)?;
+    let cached_elf = crate_dir.join("target/elf-cache").join(&hash);
+    if !force && cached_elf.exists() {
+        println!("Using the cached ELF of programs/{} ({})", guest.dir, hash);
+        fs::create_dir_all(elf_path.parent().unwrap())?;
+        fs::copy(&cached_elf, &elf_path)?;
+        return Ok(());
+    }
+
+    println!("Building programs/{} with features \"{}\"", guest.dir, features);
+    let build_dir =
+        if unpatched { copy_unpatched(&crate_dir, &manifest)? } else { crate_dir.clone() };
+    let mut cargo = Command::new("cargo");
+    cargo
+        .current_dir(&build_dir)
+        .env("RUSTFLAGS", &rustflags)
+        .envs(profile_env)
+        .arg(format!("+{}", toolchain.name))
+        .args(["build", "--release", "--target", toolchain.target, "--features", &features]);
+    match prover {
+        ProverId::SP1 => {
+            cargo.arg("--ignore-rust-version");
+        }
+        ProverId::Risc0 => {
+            cargo.env("CC", "gcc").env("CC_riscv32im_risc0_zkvm_elf", risc0_cc());
+        }
+    }
+    if locked {
+        cargo.arg("--locked");
+    }
+    if unpatched {
+        // Build next to the accelerated build, in `target/unpatched`.
+        cargo.args(["--target-dir", ".."]);
+    }
+    let status = cargo.status()?;
+    if !status.success() {
+        return Err(BuildError::Cargo { guest: guest.dir.clone(), status });
+    }
+
+    fs::create_dir_all(cached_elf.parent().unwrap())?;
+    fs::copy(&elf_path, &cached_elf)?;
+    Ok(())
+}
+
+/// Copies the guest crate to `target/unpatched/crate` without its `[patch.crates-io]` section and
+/// lockfile, and returns the directory of the copy. The relative paths in the manifest, such as the
+/// shared sources of the workload and `guest-io`, are made absolute so they resolve from the copy.
+fn copy_unpatched(crate_dir: &Path, manifest: &str) -> io::Result<PathBuf> {
+    let copy_dir = crate_dir.join("target/unpatched/crate");
+    if copy_dir.exists() {
+        fs::remove_dir_all(&copy_dir)?;
+    }
+    for path in source_files(crate_dir)? {
+        if path == Path::new("Cargo.lock") {
+            continue;
+        }
+        let copy_path = copy_dir.join(&path);
+        fs::create_dir_all(copy_path.parent().unwrap())?;
+        fs::copy(crate_dir.join(&path), copy_path)?;
+    }
+
+    let mut in_patch = false;
+    let manifest = manifest
+        .lines()
+        .filter(|line| {
+            if line.starts_with('[') {
+                in_patch = line.trim() == "[patch.crates-io]";
+            }
+            !in_patch
+        })
+        .map(|line| match manifest_path(line) {
+            Some(path) => {
+                let absolute = crate_dir.join(path).canonicalize()?;
+                Ok(format!("{}\n", line.replacen(path, &absolute.to_string_lossy(), 1)))
+            }
+            None => Ok(format!("{}\n", line)),
+        })
+        .collect::<io::Result<String>>()?;
+    fs::write(copy_dir.join("Cargo.toml"), manifest)?;
+    Ok(copy_dir)
+}
+
+/// Returns the relative path that a line of a guest manifest points at, if any, such as the
+/// `path = "../src/main.rs"` of a `[[bin]]` or the path of a `guest-io` dependency.
+fn manifest_path(line: &str) -> Option<&str> {
+    let (_, rest) = line.split_once("path = \"")?;
+    let (path, _) = rest.split_once('"')?;
+    Path::new(path).is_relative().then_some(path)
+}
+
+/// Returns the SHA-256 of the guest crate's files, the files of the directories its manifest points
+/// at, and the given build settings, as hex.
+fn source_hash(crate_dir: &Path, manifest: &str, settings: &str) -> io::Result<String> {
+    let mut dirs = vec![crate_dir.to_path_buf()];
+    for path in manifest.lines().filter_map(manifest_path) {
+        let path = crate_dir.join(path);
+        dirs.push(if path.is_file() { path.parent().unwrap().to_path_buf() } else { path });
+    }
+    dirs.sort();
+    dirs.dedup();
+
+    let mut hasher = Sha256::new();
+    hasher.update(settings);
+    for dir in dirs {
+        for path in source_files(&dir)? {
+            let contents = fs::read(dir.join(&path))?;
+            hasher.update(dir.join(&path).to_string_lossy().as_bytes());
+            hasher.update((contents.len() as u64).to_le_bytes());
+            hasher.update(contents);
+        }
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Returns the paths of the guest crate's files relative to its directory, in order, leaving out
+/// its build outputs and checked-in ELFs.
+fn source_files(crate_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(crate_dir.join(&dir))? {
+            let entry = entry?;
+            let path = dir.join(entry.file_name());
+            if path == Path::new("target") || path == Path::new("elf") {
+                continue;
+            }
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,114 @@
+use std::{collections::HashSet, fs, process::Command, thread};
+
+/// The machine and build of the eval binary that a report was measured on.
+pub struct Host {
+    /// The model name of the CPU.
+    pub cpu_model: String,
+    /// The number of physical CPU cores.
+    pub cpu_cores: usize,
+    /// The number of hardware threads.
+    pub cpu_threads: usize,
+    /// The SIMD target features the eval binary was compiled with, e.g. `avx2 avx512ifma`.
+    pub target_features: String,
+    /// The total memory of the machine in bytes.
+    pub total_memory: u64,
+    /// The names of the GPUs that `nvidia-smi` reports, if any.
+    pub gpus: Option<String>,
+    /// The version of the rustc that built the eval binary.
+    pub rustc_version: String,
+    /// The version of `sp1-sdk` the eval binary was built with.
+    pub sp1_version: String,
+    /// The version of `risc0-zkvm` the eval binary was built with, if built with `risc0`.
+    pub risc0_version: Option<String>,
+    /// The cargo features the eval binary was built with.
+    pub features: String,
+}
+
+impl Host {
+    /// Detects the host. The CPU and memory are read from `/proc`, and are left empty elsewhere.
+    pub fn detect() -> Self {
+        let cpuinfo = fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+        let meminfo = fs::read_to_string("/proc/meminfo").unwrap_or_default();
+
+        let cpu_model = cpuinfo_values(&cpuinfo, "model name").next().unwrap_or_default();
+        let cpu_threads = match cpuinfo_values(&cpuinfo, "processor").count() {
+            0 => thread::available_parallelism().map(|threads| threads.get()).unwrap_or(0),
+            threads => threads,
+        };
+        // A core is identified by its package and its ID within the package.
+        let cores = cpuinfo_values(&cpuinfo, "physical id")
+            .zip(cpuinfo_values(&cpuinfo, "core id"))
+            .collect::<HashSet<_>>();
+        let cpu_cores = if cores.is_empty() { cpu_threads } else { cores.len() };
+
+        let total_memory = meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix("MemTotal:"))
+            .and_then(|kb| kb.trim().trim_end_matches("kB").trim().parse::<u64>().ok())
+            .map(|kb| kb * 1024)
+            .unwrap_or(0);
+
+        let gpus = Command::new("nvidia-smi")
+            .args(["--query-gpu=name", "--format=csv,noheader"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                stdout.lines().map(str::trim).collect::<Vec<_>>().join("; ")
+            })
+            .filter(|gpus| !gpus.is_empty());
+
+        Host {
+            cpu_model,
+            cpu_cores,
+            cpu_threads,
+            target_features: target_features(),
+            total_memory,
+            gpus,
+            rustc_version: env!("EVAL_RUSTC_VERSION").to_string(),
+            sp1_version: env!("EVAL_SP1_VERSION").to_string(),
+            risc0_version: cfg!(feature = "risc0").then(|| env!("EVAL_RISC0_VERSION").to_string()),
+            features: features(),
+        }
+    }
+}
+
+/// Returns the values of a field of `/proc/cpuinfo`, one per processor.
+fn cpuinfo_values<'a>(cpuinfo: &'a str, field: &'a str) -> impl Iterator<Item = String> + 'a {
+    cpuinfo.lines().filter_map(move |line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == field).then(|| value.trim().to_string())
+    })
+}
+
+/// Returns the SIMD target features the eval binary was compiled with, which `eval.sh` picks with
+/// RUSTFLAGS depending on the CPU.
+fn target_features() -> String {
+    enabled(&[
+        ("avx2", cfg!(target_feature = "avx2")),
+        ("avx512f", cfg!(target_feature = "avx512f")),
+        ("avx512vl", cfg!(target_feature = "avx512vl")),
+        ("avx512ifma", cfg!(target_feature = "avx512ifma")),
+        ("neon", cfg!(target_feature = "neon")),
+    ])
+}
+
+/// Returns the cargo features the eval binary was built with.
+fn features() -> String {
+    enabled(&[
+        ("sp1", cfg!(feature = "sp1")),
+        ("risc0", cfg!(feature = "risc0")),
+        ("cuda", cfg!(feature = "cuda")),
+    ])
+}
+
+/// Returns the names of the enabled flags, separated by spaces.
+fn enabled(flags: &[(&str, bool)]) -> String {
+    flags
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,65 @@
+use std::{env, path::Path};
+
+use clap::{Args, ValueEnum};
+
+use crate::{utils::program_elf_path, Precompiles, ProgramId, ProverId};
+
+/// The arguments of the `list` subcommand.
+#[derive(Args, Clone)]
+pub struct ListArgs {
+    /// The prover whose guests to list. Defaults to every prover.
+    #[arg(long)]
+    prover: Option<ProverId>,
+    /// Whether to list the builds with their accelerated crates, the unpatched builds without
+    /// them, or both.
+    #[arg(long, env = "PRECOMPILES", value_enum, default_value_t = Precompiles::On)]
+    precompiles: Precompiles,
+}
+
+/// Prints each program with the path of its guest's ELF and whether the ELF is built.
+pub fn list(args: &ListArgs) {
+    let provers = match &args.prover {
+        Some(prover) => vec![prover.clone()],
+        None => ProverId::value_variants().to_vec(),
+    };
+    let current_dir = env::current_dir().expect("Failed to get current working directory");
+
+    let mut rows = Vec::new();
+    for prover in &provers {
+        for program in ProgramId::value_variants() {
+            let guest = program.guest(prover);
+            // The baseline programs only prove the unpatched build.
+            let precompiles =
+                if program.is_baseline() { Precompiles::Off } else { args.precompiles.clone() };
+            for unpatched in [false, true] {
+                if (unpatched && precompiles == Precompiles::On)
+                    || (!unpatched && precompiles == Precompiles::Off)
+                {
+                    continue;
+                }
+                let elf_path = program_elf_path(&guest, prover, unpatched);
+                let built = Path::new(&elf_path).exists();
+                let elf_path = Path::new(&elf_path)
+                    .strip_prefix(&current_dir)
+                    .map(|path| path.display().to_string())
+                    .unwrap_or(elf_path.clone());
+                rows.push((
+                    program.to_possible_value().unwrap().get_name().to_string(),
+                    prover.to_string(),
+                    elf_path,
+                    if built { "built" } else { "missing" },
+                ));
+            }
+        }
+    }
+
+    let program_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0);
+    let prover_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0);
+    let path_width = rows.iter().map(|row| row.2.len()).max().unwrap_or(0);
+    for (program, prover, elf_path, status) in rows {
+        println!(
+            "{:<program_width$}  {:<prover_width$}  {:<path_width$}  {}",
+            program, prover, elf_path, status
+        );
+    }
+}
@@ -1,21 +1,43 @@
+mod build;
+mod host;
+mod list;
 mod risc0;
 mod sp1;
+mod supervise;
 mod types;
 mod utils;
 
 use std::{
+    env,
+    fmt::{Display, Formatter},
     fs::{create_dir_all, OpenOptions},
     path::PathBuf,
+    process,
 };
 
-use clap::{command, Parser};
+use build::{BuildArgs, GuestProfile};
+use clap::{command, error::ErrorKind, Args, CommandFactory, Parser};
 use csv::WriterBuilder;
+use host::Host;
+use list::ListArgs;
 use serde::Serialize;
+use supervise::Outcome;
 use types::*;
 
-/// The argument passed through the CLI.
-#[derive(Parser, Clone)]
+/// The command passed through the CLI.
+#[derive(Parser)]
 #[command(about = "Evaluate the performance of a zkVM on a program.")]
+enum Cli {
+    /// Evaluate a prover on a program.
+    Run(EvalArgs),
+    /// Build the guests of programs for a prover.
+    Build(BuildArgs),
+    /// List the programs with the ELF path of their guests and whether it is built.
+    List(ListArgs),
+}
+
+/// The argument passed through the CLI.
+#[derive(Args, Clone)]
 pub struct EvalArgs {
     #[arg(long)]
     program: ProgramId,
@@ -33,6 +55,77 @@ pub struct EvalArgs {
     groth16: bool,
     #[arg(long)]
     plonk: bool,
+    /// The kind of receipt RISC Zero compresses the core proof into.
+    #[arg(long, env = "RISC0_RECEIPT_KIND", value_enum, default_value_t = Risc0ReceiptKind::Succinct)]
+    risc0_receipt_kind: Risc0ReceiptKind,
+    /// The maximum segment size RISC Zero proves, as a power of two.
+    #[arg(long, env = "RISC0_MAX_SEGMENT_PO2")]
+    risc0_max_segment_po2: Option<usize>,
+    /// Whether the RISC Zero guest was built with the keccak accelerator.
+    #[arg(long, env = "RISC0_FEATURE_keccak", value_parser = clap::builder::BoolishValueParser::new())]
+    risc0_keccak: bool,
+    /// Whether the RISC Zero guest was built with the bigint2 accelerator.
+    #[arg(long, env = "RISC0_FEATURE_bigint2", value_parser = clap::builder::BoolishValueParser::new())]
+    risc0_bigint2: bool,
+    /// How the tendermint light blocks are encoded for the guest.
+    #[arg(long, env = "TENDERMINT_ENCODING", value_enum, default_value_t = TendermintEncoding::Protobuf)]
+    tendermint_encoding: TendermintEncoding,
+    /// The tendermint light client trust threshold, as `numerator/denominator`.
+    #[arg(
+        long,
+        env = "TENDERMINT_TRUST_THRESHOLD",
+        default_value = "1/3",
+        value_parser = utils::parse_trust_threshold
+    )]
+    tendermint_trust_threshold: (u64, u64),
+    /// The tendermint light client trusting period in seconds. Defaults to a period covering the
+    /// height gap of the program's light blocks.
+    #[arg(long, env = "TENDERMINT_TRUSTING_PERIOD_SECS")]
+    tendermint_trusting_period_secs: Option<u64>,
+    /// The tendermint light client clock drift in seconds.
+    #[arg(long, env = "TENDERMINT_CLOCK_DRIFT_SECS", default_value_t = 0)]
+    tendermint_clock_drift_secs: u64,
+    /// The number of signatures verified by the signature programs. Defaults to 100 for
+    /// `eddsa-verify` and 1 for the others.
+    #[arg(long, env = "SIGNATURE_COUNT")]
+    signature_count: Option<u32>,
+    /// Whether the signatures come from distinct signers or from one repeated signer.
+    #[arg(long, env = "SIGNERS", value_enum, default_value_t = Signers::Distinct)]
+    signers: Signers,
+    /// The size in bytes of each message the hashing programs hash separately. Defaults to hashing
+    /// the whole input as one message.
+    #[arg(long, env = "HASH_MESSAGE_SIZE", value_parser = clap::value_parser!(u32).range(1..))]
+    hash_message_size: Option<u32>,
+    /// Whether the hashing programs hash zero bytes or random bytes drawn from `--seed`.
+    #[arg(long, env = "HASH_BYTES", value_enum, default_value_t = HashBytes::Zero)]
+    hash_bytes: HashBytes,
+    /// The seed of the randomly generated guest inputs, so that reruns prove identical inputs.
+    #[arg(long, env = "SEED", default_value_t = 0)]
+    seed: u64,
+    /// Whether to prove the guest built with its accelerated crates, the unpatched build without
+    /// them, or both side by side.
+    #[arg(long, env = "PRECOMPILES", value_enum, default_value_t = Precompiles::On)]
+    precompiles: Precompiles,
+    /// Whether to prove the ELF built from the guest's sources, the ELF checked in next to them,
+    /// or the ELF at `--elf-path`.
+    #[arg(long, env = "ELF_SOURCE", value_enum, default_value_t = ElfSource::Built)]
+    elf_source: ElfSource,
+    /// The ELF to prove with `--elf-source path`.
+    #[arg(long, env = "ELF_PATH", required_if_eq("elf_source", "path"))]
+    elf_path: Option<String>,
+    /// The compiler settings the guest was built with, which `eval build` takes as well.
+    #[command(flatten)]
+    guest_profile: GuestProfile,
+    /// The number of threads the rayon pools that both provers prove with, and SP1's trace
+    /// generation workers, may use. Defaults to every hardware thread.
+    #[arg(long, env = "THREADS")]
+    threads: Option<usize>,
+    /// The number of seconds after which the evaluation is killed and recorded as `timeout`.
+    #[arg(long, env = "TIMEOUT")]
+    timeout: Option<u64>,
+    /// The resident memory in MiB past which the evaluation is killed and recorded as `oom`.
+    #[arg(long, env = "MAX_RSS")]
+    max_rss: Option<u64>,
 }
 
 /// The performance report of a zkVM on a program.
@@ -53,6 +146,10 @@ pub struct PerformanceReport {
     ///
     /// Note that this number may vary based on the zkVM.
     pub cycles: u64,
+    /// The reported number of cycles spent paging memory in and out, on top of `cycles`.
+    ///
+    /// Only reported for RISC Zero, as SP1 accounts for memory in its memory chips instead.
+    pub paging_cycles: Option<u64>,
     /// The reported speed in cycles per second.
     pub speed: f64,
     /// The reported duration of the execution in seconds.
@@ -87,26 +184,325 @@ pub struct PerformanceReport {
 
     /// The overall speed in KHz.
     pub overall_khz: f64,
-    /// The number of hashes per second
+    /// The number of hash blocks (compression function calls or permutations) per second
     pub hashes_per_second: Option<f64>,
     /// The number of bytes hashed per second
     pub hash_bytes_per_second: Option<f64>,
     /// The gas used by the program (if RSP)
     pub gas: Option<u64>,
+
+    /// The kind of receipt the core proof was compressed into (RISC Zero only).
+    pub receipt_kind: Option<String>,
+    /// The maximum segment size as a power of two (RISC Zero only).
+    pub max_segment_po2: Option<usize>,
+    /// Whether the guest was built with the keccak accelerator (RISC Zero only).
+    pub keccak_accel: Option<bool>,
+    /// Whether the guest was built with the bigint2 accelerator (RISC Zero only).
+    pub bigint2_accel: Option<bool>,
+
+    /// The reported duration of the first recursion layer (lifting core proofs) in seconds.
+    ///
+    /// Not reported for SP1, which pipelines the first layer with the rest of the tree, nor for
+    /// RISC Zero composite receipts, which are not lifted.
+    pub compress_lift_duration: Option<f64>,
+    /// The number of proofs in the first recursion layer (core and deferred proofs).
+    ///
+    /// Not reported for SP1, which does not expose the shape of its recursion tree.
+    pub compress_lift_count: Option<usize>,
+    /// The reported duration of the remaining recursion layers (joining proofs) in seconds.
+    ///
+    /// Not reported for SP1, which pipelines the first layer with the rest of the tree, nor for
+    /// RISC Zero composite receipts, which are not joined.
+    pub compress_join_duration: Option<f64>,
+    /// The reported duration of resolving the assumptions into the joined proof in seconds.
+    ///
+    /// RISC Zero succinct and groth16 receipts only. SP1 verifies deferred proofs in its tree.
+    pub compress_resolve_duration: Option<f64>,
+    /// The number of joins performed while reducing the recursion tree. Not reported for SP1.
+    pub compress_join_count: Option<usize>,
+    /// The depth of the join tree. Not reported for SP1.
+    pub compress_tree_depth: Option<usize>,
+    /// The total number of recursion programs executed during compression. Not reported for SP1.
+    pub recursion_programs: Option<usize>,
+    /// The number of deferred proofs verified during compression.
+    pub deferred_proofs: usize,
+
+    /// The reported duration of proving the inner programs of an aggregation in seconds.
+    pub inner_prove_duration: Option<f64>,
+    /// The reported duration of the inner proofs plus the outer aggregation proof in seconds.
+    pub aggregation_prove_duration: Option<f64>,
+
+    /// The encoding of the light blocks passed to the guest (tendermint only).
+    pub tendermint_encoding: Option<String>,
+
+    /// The number of signatures verified (signature programs only).
+    pub signature_count: Option<u32>,
+    /// Whether the signatures came from distinct or repeated signers (signature programs only).
+    pub signers: Option<String>,
+
+    /// The size of each message hashed separately (hashing programs only).
+    pub hash_message_size: Option<u32>,
+    /// Whether zero or random bytes were hashed (hashing programs only).
+    pub hash_bytes: Option<String>,
+
+    /// The seed of the randomly generated guest inputs.
+    pub seed: u64,
+
+    /// Whether the guest was proven with its accelerated crates, without them, or both.
+    pub precompiles: String,
+    /// The number of cycles of the unpatched build (`--precompiles ab` only).
+    pub baseline_cycles: Option<u64>,
+    /// The reported duration of proving the unpatched build in seconds (`--precompiles ab` only).
+    pub baseline_prove_duration: Option<f64>,
+    /// How many times faster the accelerated build proves than the unpatched build
+    /// (`--precompiles ab` only).
+    pub speedup: Option<f64>,
+
+    /// Whether the proven ELF was built, checked in, or given by path.
+    pub elf_source: String,
+    /// The SHA-256 of the proven ELF, as hex.
+    pub elf_sha256: String,
+    /// The size of the proven ELF in bytes.
+    pub elf_size: u64,
+    /// The size in bytes of the executable segments of the proven ELF.
+    pub elf_text_size: u64,
+    /// The SP1 verifying key hash or the RISC Zero image ID of the proven ELF.
+    pub vkey: String,
+
+    /// The optimization level the guest was built with (built ELFs only).
+    pub guest_opt_level: Option<String>,
+    /// The link-time optimization the guest was built with, if not the one in its manifest (built
+    /// ELFs only).
+    pub guest_lto: Option<String>,
+    /// The number of codegen units the guest was built with (built ELFs only).
+    pub guest_codegen_units: Option<u32>,
+    /// The panic strategy the guest was built with (built ELFs only).
+    pub guest_panic: Option<String>,
+
+    /// The model name of the host CPU.
+    pub cpu_model: String,
+    /// The number of physical cores of the host CPU.
+    pub cpu_cores: usize,
+    /// The number of hardware threads of the host CPU.
+    pub cpu_threads: usize,
+    /// The SIMD target features the eval binary was compiled with.
+    pub target_features: String,
+    /// The total memory of the host in bytes.
+    pub total_memory: u64,
+    /// The GPUs of the host, if any.
+    pub gpus: Option<String>,
+    /// The version of the rustc that built the eval binary.
+    pub rustc_version: String,
+    /// The version of `sp1-sdk` the eval binary was built with.
+    pub sp1_version: String,
+    /// The version of `risc0-zkvm` the eval binary was built with (`risc0` feature only).
+    pub risc0_version: Option<String>,
+    /// The cargo features the eval binary was built with.
+    pub features: String,
+    /// The number of threads the prover was limited to, or the number of hardware threads.
+    pub threads: usize,
+
+    /// How the evaluation ended. The measurements are left empty unless it is `ok`.
+    pub status: String,
+    /// The error that stopped the evaluation, or the message the evaluator panicked with.
+    pub error: Option<String>,
+}
+
+/// An error that stopped a program from being evaluated.
+#[derive(Debug)]
+pub enum EvalError {
+    /// The ELF to prove does not exist or has no code.
+    MissingElf(String),
+    /// The guest panicked, or halted with a non-zero exit code.
+    GuestPanic { message: String, exit_code: Option<u32> },
+    /// The prover failed to set up, prove or compress.
+    Prove(String),
+    /// A proof failed to verify.
+    Verify(String),
+    /// A proof failed to serialize.
+    Serialize(String),
+}
+
+impl EvalError {
+    /// Wraps an error returned by a prover while setting up, proving or compressing.
+    pub fn prove(err: impl Display) -> Self {
+        EvalError::Prove(err.to_string())
+    }
+
+    /// Wraps an error returned by a verifier.
+    pub fn verify(err: impl Display) -> Self {
+        EvalError::Verify(err.to_string())
+    }
+
+    /// Wraps an error returned while serializing a proof.
+    pub fn serialize(err: impl Display) -> Self {
+        EvalError::Serialize(err.to_string())
+    }
+
+    /// Returns the status that the failed evaluation is recorded with.
+    pub fn status(&self) -> RunStatus {
+        match self {
+            EvalError::MissingElf(_) => RunStatus::MissingElf,
+            EvalError::GuestPanic { .. } => RunStatus::GuestPanic,
+            EvalError::Prove(_) => RunStatus::ProveFailed,
+            EvalError::Verify(_) => RunStatus::VerifyFailed,
+            EvalError::Serialize(_) => RunStatus::SerializeFailed,
+        }
+    }
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::MissingElf(err) => write!(f, "{}", err),
+            EvalError::GuestPanic { message, exit_code: Some(exit_code) } => {
+                write!(f, "the guest exited with code {}: {}", exit_code, message)
+            }
+            EvalError::GuestPanic { message, exit_code: None } => {
+                write!(f, "the guest panicked: {}", message)
+            }
+            EvalError::Prove(err) => write!(f, "proving failed: {}", err),
+            EvalError::Verify(err) => write!(f, "verification failed: {}", err),
+            EvalError::Serialize(err) => write!(f, "serializing a proof failed: {}", err),
+        }
+    }
+}
+
+/// Select the correct implementation based on the prover.
+fn eval(args: &EvalArgs) -> Result<PerformanceReport, EvalError> {
+    match args.prover {
+        ProverId::Risc0 => risc0::Risc0Evaluator::eval(args),
+        ProverId::SP1 => sp1::SP1Evaluator::eval(args),
+    }
 }
 
 fn main() {
     // Setup the logger.
     sp1_core_machine::utils::setup_logger();
 
-    let args = EvalArgs::parse();
-
-    // Select the correct implementation based on the prover.
-    let report = match args.prover {
-        ProverId::Risc0 => risc0::Risc0Evaluator::eval(&args),
-        ProverId::SP1 => sp1::SP1Evaluator::eval(&args),
+    let mut args = match Cli::parse() {
+        Cli::Run(args) => args,
+        Cli::Build(args) => {
+            if let Err(err) = build::build(&args) {
+                eprintln!("error: {}", err);
+                process::exit(1);
+            }
+            return;
+        }
+        Cli::List(args) => {
+            list::list(&args);
+            return;
+        }
     };
 
+    // The baseline programs always prove the unpatched build of their guest, so there is nothing to
+    // compare them with.
+    if args.program.is_baseline() {
+        if args.precompiles == Precompiles::Ab {
+            Cli::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    format!("{} cannot be run with `--precompiles ab`", args.program),
+                )
+                .exit();
+        }
+        args.precompiles = Precompiles::Off;
+    }
+    // Only the ELF built with its accelerated crates is checked in or passed by path.
+    if args.precompiles != Precompiles::On && args.elf_source != ElfSource::Built {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "the unpatched build of a guest can only be proven with `--elf-source built`",
+            )
+            .exit();
+    }
+
+    // Run the evaluation in a child process, so that a run that panics or is killed still writes a
+    // row with how it ended.
+    if env::var(supervise::CHILD_ENV).is_err() {
+        match supervise::supervise(&args).expect("Failed to run the evaluation") {
+            Outcome::Ok => return,
+            Outcome::Failed => process::exit(1),
+            Outcome::Killed(status, panic_message) => {
+                let mut report = failure_report(&args, status);
+                report.error = panic_message;
+                write_report(&args, report);
+                process::exit(1);
+            }
+        }
+    }
+    supervise::record_panics();
+
+    // Limit the rayon pools, which read the number of threads when they are first used.
+    if let Some(threads) = args.threads {
+        env::set_var("RAYON_NUM_THREADS", threads.to_string());
+    }
+
+    match evaluate(&args) {
+        Ok(mut report) => {
+            report.status = RunStatus::Ok.to_string();
+            write_report(&args, report);
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            let mut report = failure_report(&args, err.status());
+            report.error = Some(err.to_string());
+            write_report(&args, report);
+            process::exit(supervise::FAILED_EXIT_CODE);
+        }
+    }
+}
+
+/// Evaluates the program, or with `--precompiles ab` evaluates the accelerated and the unpatched
+/// build one after the other for an A/B comparison.
+fn evaluate(args: &EvalArgs) -> Result<PerformanceReport, EvalError> {
+    match args.precompiles {
+        Precompiles::Ab => {
+            let mut report = eval(&EvalArgs { precompiles: Precompiles::On, ..args.clone() })?;
+            let baseline = eval(&EvalArgs { precompiles: Precompiles::Off, ..args.clone() })?;
+            report.precompiles = args.precompiles.to_string();
+            report.baseline_cycles = Some(baseline.cycles);
+            report.baseline_prove_duration = Some(baseline.prove_duration);
+            report.speedup = Some(baseline.prove_duration / report.prove_duration);
+            Ok(report)
+        }
+        _ => eval(args),
+    }
+}
+
+/// Returns the report of a failed evaluation, which only has the options it was run with.
+fn failure_report(args: &EvalArgs, status: RunStatus) -> PerformanceReport {
+    PerformanceReport {
+        program: args.program.to_string(),
+        priority: args.program.priority(),
+        prover: args.prover.to_string(),
+        hashfn: args.hashfn.to_string(),
+        shard_size: args.shard_size,
+        seed: args.seed,
+        precompiles: args.precompiles.to_string(),
+        elf_source: args.elf_source.to_string(),
+        status: status.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Appends the report to the results file of `--filename`, along with the host it was measured on.
+fn write_report(args: &EvalArgs, mut report: PerformanceReport) {
+    // Record the machine and build that the report was measured on.
+    let host = Host::detect();
+    report.cpu_model = host.cpu_model;
+    report.cpu_cores = host.cpu_cores;
+    report.cpu_threads = host.cpu_threads;
+    report.target_features = host.target_features;
+    report.total_memory = host.total_memory;
+    report.gpus = host.gpus;
+    report.rustc_version = host.rustc_version;
+    report.sp1_version = host.sp1_version;
+    report.risc0_version = host.risc0_version;
+    report.features = host.features;
+    report.threads = args.threads.unwrap_or(report.cpu_threads);
+
     // Create the results directory if it doesn't exist.
     let results_dir = PathBuf::from("benchmarks");
     create_dir_all(&results_dir).unwrap();
@@ -146,6 +542,53 @@ fn main() {
                 "wrap_prove_duration",
                 "groth16_prove_duration",
                 "plonk_prove_duration",
+                "receipt_kind",
+                "max_segment_po2",
+                "keccak_accel",
+                "bigint2_accel",
+                "compress_lift_duration",
+                "compress_lift_count",
+                "compress_join_duration",
+                "compress_join_count",
+                "compress_tree_depth",
+                "recursion_programs",
+                "deferred_proofs",
+                "inner_prove_duration",
+                "aggregation_prove_duration",
+                "tendermint_encoding",
+                "signature_count",
+                "signers",
+                "seed",
+                "hash_message_size",
+                "hash_bytes",
+                "paging_cycles",
+                "precompiles",
+                "baseline_cycles",
+                "baseline_prove_duration",
+                "speedup",
+                "elf_source",
+                "elf_sha256",
+                "elf_size",
+                "elf_text_size",
+                "vkey",
+                "guest_opt_level",
+                "guest_lto",
+                "guest_codegen_units",
+                "guest_panic",
+                "cpu_model",
+                "cpu_cores",
+                "cpu_threads",
+                "target_features",
+                "total_memory",
+                "gpus",
+                "rustc_version",
+                "sp1_version",
+                "risc0_version",
+                "features",
+                "threads",
+                "status",
+                "error",
+                "compress_resolve_duration",
             ])
             .unwrap();
     }
@@ -176,6 +619,53 @@ fn main() {
             report.wrap_prove_duration.to_string(),
             report.groth16_prove_duration.to_string(),
             report.plonk_prove_duration.to_string(),
+            report.receipt_kind.unwrap_or_default(),
+            report.max_segment_po2.map(|x| x.to_string()).unwrap_or_default(),
+            report.keccak_accel.map(|x| x.to_string()).unwrap_or_default(),
+            report.bigint2_accel.map(|x| x.to_string()).unwrap_or_default(),
+            report.compress_lift_duration.map(|x| x.to_string()).unwrap_or_default(),
+            report.compress_lift_count.map(|x| x.to_string()).unwrap_or_default(),
+            report.compress_join_duration.map(|x| x.to_string()).unwrap_or_default(),
+            report.compress_join_count.map(|x| x.to_string()).unwrap_or_default(),
+            report.compress_tree_depth.map(|x| x.to_string()).unwrap_or_default(),
+            report.recursion_programs.map(|x| x.to_string()).unwrap_or_default(),
+            report.deferred_proofs.to_string(),
+            report.inner_prove_duration.map(|x| x.to_string()).unwrap_or_default(),
+            report.aggregation_prove_duration.map(|x| x.to_string()).unwrap_or_default(),
+            report.tendermint_encoding.unwrap_or_default(),
+            report.signature_count.map(|x| x.to_string()).unwrap_or_default(),
+            report.signers.unwrap_or_default(),
+            report.seed.to_string(),
+            report.hash_message_size.map(|x| x.to_string()).unwrap_or_default(),
+            report.hash_bytes.unwrap_or_default(),
+            report.paging_cycles.map(|x| x.to_string()).unwrap_or_default(),
+            report.precompiles,
+            report.baseline_cycles.map(|x| x.to_string()).unwrap_or_default(),
+            report.baseline_prove_duration.map(|x| x.to_string()).unwrap_or_default(),
+            report.speedup.map(|x| x.to_string()).unwrap_or_default(),
+            report.elf_source,
+            report.elf_sha256,
+            report.elf_size.to_string(),
+            report.elf_text_size.to_string(),
+            report.vkey,
+            report.guest_opt_level.unwrap_or_default(),
+            report.guest_lto.unwrap_or_default(),
+            report.guest_codegen_units.map(|x| x.to_string()).unwrap_or_default(),
+            report.guest_panic.unwrap_or_default(),
+            report.cpu_model,
+            report.cpu_cores.to_string(),
+            report.cpu_threads.to_string(),
+            report.target_features,
+            report.total_memory.to_string(),
+            report.gpus.unwrap_or_default(),
+            report.rustc_version,
+            report.sp1_version,
+            report.risc0_version.unwrap_or_default(),
+            report.features,
+            report.threads.to_string(),
+            report.status,
+            report.error.unwrap_or_default(),
+            report.compress_resolve_duration.map(|x| x.to_string()).unwrap_or_default(),
         ])
         .unwrap();
     writer.flush().unwrap();
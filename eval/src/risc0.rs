@@ -1,43 +1,82 @@
-#[cfg(feature = "risc0")]
-use std::fs;
-
 #[cfg(feature = "risc0")]
 use crate::{
     utils::{
-        gas_amount, get_elf, hash_bytes_per_second, hashes_per_second,
-        rand_ecdsa_signature, rand_eddsa_signature, time_operation,
+        aggregation_inner_elf, aggregation_outputs_digest, aggregation_size, elf_sha256,
+        elf_text_size, gas_amount, get_elf, guest_profile, hash_bytes, hash_bytes_per_second,
+        hash_input, hash_input_size_bytes, hash_message_size, hashes_per_second, helios_fixture,
+        memory_input, modexp_input, mulmod256_input, pairing_scalars, signature_batch,
+        signature_count, signers, ssz_withdrawals_params, tendermint_encoding, tendermint_input,
+        time_operation, HeliosOutputs, GROTH16_PROOF, GROTH16_PUBLIC_INPUTS,
+        GROTH16_VERIFICATION_KEY,
     },
-    HashFnId, ProgramId,
-};
-#[cfg(feature = "risc0")]
-use risc0_zkvm::{
-    compute_image_id, get_prover_server, ExecutorEnv, ExecutorImpl, ProverOpts, VerifierContext,
+    HashFnId, ProgramId, ProverId, Risc0ReceiptKind,
 };
 #[cfg(feature = "risc0")]
 use risc0_groth16::{
     Fr, ProofJson, PublicInputsJson, Seal, Verifier, VerifyingKey, VerifyingKeyJson,
 };
+#[cfg(feature = "risc0")]
+use risc0_zkvm::{
+    compute_image_id, get_prover_server, ExecutorEnv, ExecutorImpl, ExitCode,
+    InnerAssumptionReceipt, InnerReceipt, ProverOpts, Receipt, ReceiptKind, Session,
+    VerifierContext,
+};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{EvalArgs, PerformanceReport};
+use crate::{EvalArgs, EvalError, PerformanceReport};
 use std::time::Duration;
 
 pub struct Risc0Evaluator;
 
 impl Risc0Evaluator {
     #[cfg(feature = "risc0")]
-    pub fn eval(args: &EvalArgs) -> PerformanceReport {
+    pub fn eval(args: &EvalArgs) -> Result<PerformanceReport, EvalError> {
         use crate::ProgramId;
 
-        if args.hashfn != HashFnId::Poseidon {
-            panic!("Only Poseidon hash function is supported for Risc0.");
-        }
+        let hashfn = match args.hashfn {
+            HashFnId::Poseidon => "poseidon2",
+            HashFnId::Sha256 => "sha-256",
+            HashFnId::Blake2b => "blake2b",
+            _ => return Err(EvalError::Prove(format!("risc0 does not support {}", args.hashfn))),
+        };
+        let receipt_kind = match args.risc0_receipt_kind {
+            Risc0ReceiptKind::Composite => ReceiptKind::Composite,
+            Risc0ReceiptKind::Succinct => ReceiptKind::Succinct,
+            Risc0ReceiptKind::Groth16 => ReceiptKind::Groth16,
+        };
 
-        let elf_path = get_elf(args);
-        let elf = fs::read(&elf_path).unwrap();
-        let image_id = compute_image_id(elf.as_slice()).unwrap();
+        let elf = get_elf(args)?;
+        let image_id = compute_image_id(elf.as_slice()).map_err(EvalError::prove)?;
+
+        // Prove the inner programs of an aggregation up front, so that both executions below can
+        // add their receipts as assumptions.
+        let mut inner_receipts = Vec::new();
+        let mut inner_image_id = [0u32; 8];
+        let mut inner_prove_duration = None;
+        if let Some(num_proofs) = aggregation_size(&args.program) {
+            let inner_elf = aggregation_inner_elf(&ProverId::Risc0)?;
+            inner_image_id = compute_image_id(&inner_elf).map_err(EvalError::prove)?.into();
+
+            let inner_opts = ProverOpts::succinct();
+            let inner_prover = get_prover_server(&inner_opts).map_err(EvalError::prove)?;
+            let (receipts, tmp_inner_prove_duration) = time_operation(|| {
+                (0..num_proofs)
+                    .map(|_| {
+                        let mut builder = ExecutorEnv::builder();
+                        builder.write::<u32>(&1500).map_err(EvalError::serialize)?;
+                        let env = builder.build().map_err(EvalError::prove)?;
+                        let receipt =
+                            inner_prover.prove(env, &inner_elf).map_err(EvalError::prove)?.receipt;
+                        inner_prover.compress(&inner_opts, &receipt).map_err(EvalError::prove)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            });
+            inner_receipts = receipts?;
+            inner_prove_duration = Some(tmp_inner_prove_duration);
+        }
 
+        let mut hashed_bytes = None;
         let mut builder = ExecutorEnv::builder();
         builder.segment_limit_po2(args.shard_size as u32);
         match args.program {
@@ -89,32 +128,34 @@ impl Risc0Evaluator {
             ProgramId::Fibonacci400m => {
                 builder.write::<u32>(&30000000);
             }
-            ProgramId::Sha256100kb => {
-                builder.write(&vec![0u8; 102400]);
-            }
-            ProgramId::Sha256300kb => {
-                builder.write(&vec![0u8; 102400 * 3]);
-            }
-            ProgramId::Sha2561mb => {
-                builder.write(&vec![0u8; 1048576]);
-            }
-            ProgramId::Sha2563mb => {
-                builder.write(&vec![0u8; 1048576 * 3]);
-            }
-            ProgramId::Keccak256100kb => {
-                builder.write(&vec![0u8; 102400]);
-            }
-            ProgramId::Keccak256300kb => {
-                builder.write(&vec![0u8; 102400 * 3]);
-            }
-            ProgramId::Keccak2561mb => {
-                builder.write(&vec![0u8; 1048576]);
-            }
-            ProgramId::Keccak2563mb => {
-                builder.write(&vec![0u8; 1048576 * 3]);
-            }
-            ProgramId::Keccak25610mb => {
-                builder.write(&vec![0u8; 1048576 * 10]);
+            ProgramId::Mulmod256 | ProgramId::Mulmod256Baseline => {
+                let (input, _) = mulmod256_input(args).unwrap();
+                builder.write(&input).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Modexp2048
+            | ProgramId::Modexp2048Baseline
+            | ProgramId::Modexp4096
+            | ProgramId::Modexp4096Baseline => {
+                let (input, _) = modexp_input(args).unwrap();
+                builder.write(&input).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Bn254Pairing
+            | ProgramId::Bn254PairingBaseline
+            | ProgramId::Bls12381Pairing
+            | ProgramId::Bls12381PairingBaseline => {
+                builder.write(&pairing_scalars(args).unwrap()).map_err(EvalError::serialize)?;
+            }
+            _ if memory_input(&args.program).is_some() => {
+                let (workload, ops) = memory_input(&args.program).unwrap();
+                builder.write(&workload).map_err(EvalError::serialize)?;
+                builder.write(&ops).map_err(EvalError::serialize)?;
+                builder.write(&args.seed).map_err(EvalError::serialize)?;
+            }
+            _ if hash_input_size_bytes(&args.program).is_some() => {
+                let (message_size, data) = hash_input(args).unwrap();
+                hashed_bytes = Some(data.len() as u64);
+                builder.write(&message_size).map_err(EvalError::serialize)?;
+                builder.write(&data).map_err(EvalError::serialize)?;
             }
             ProgramId::Rsp20526626 => {
                 let input = include_bytes!("../../fixtures/20526626.bin");
@@ -155,42 +196,50 @@ impl Risc0Evaluator {
             ProgramId::Rsp20528712 => {
                 let input = include_bytes!("../../fixtures/20528712.bin");
                 builder.write(&input.to_vec());
-            },
-            ProgramId::ECDSAVerify => {
-                builder.write(&rand_ecdsa_signature());
-            },
-            ProgramId::EDDSAVerify => {
-                let times: u8 = 100;
-                builder.write(&times);
-                
-                for _ in 0..times {
-                    builder.write(&rand_eddsa_signature());
-                }
-            },
-            ProgramId::Helios => {
-                let input = include_bytes!("../../fixtures/helios/proof_inputs.cbor");
+            }
+            ProgramId::ECDSAVerify
+            | ProgramId::EDDSAVerify
+            | ProgramId::P256Verify
+            | ProgramId::SchnorrVerify
+            | ProgramId::BLSAggregateVerify => {
+                builder.write(&signature_batch(args).unwrap()).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Helios0 | ProgramId::Helios1 | ProgramId::Helios4 => {
+                let (input, _) = helios_fixture(&args.program).unwrap();
+                builder.write(&input.to_vec()).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Tendermint | ProgramId::TendermintSkipping => {
+                let (input, trusted, untrusted) = tendermint_input(args)?;
+                builder.write(&input).map_err(EvalError::serialize)?;
+                builder.write(&trusted).map_err(EvalError::serialize)?;
+                builder.write(&untrusted).map_err(EvalError::serialize)?;
+            }
+            ProgramId::SSZWithdrawals1x
+            | ProgramId::SSZWithdrawals10x
+            | ProgramId::SSZWithdrawals100x => {
+                let input = include_bytes!("../../fixtures/ssz-withdrawals/witness.bin");
+                builder.write(&ssz_withdrawals_params(&args.program).unwrap());
                 builder.write(&input.to_vec());
-            },
+            }
             ProgramId::Groth16ProofVerify => {
-                const PROOF: &str = include_str!("../../fixtures/risc0/proof.json");
-                const PUBLIC_INPUTS: &str = include_str!("../../fixtures/risc0/public.json");
-                const VERIFICATION_KEY: &str = include_str!("../../fixtures/risc0/verification_key.json");
-
-                // Verification_key, proof and public witness generated by SnarkJS using Groth16 over BN254
-                // (https://docs.circom.io/getting-started/proving-circuits/)
-                let proof_json: ProofJson = serde_json::from_str(PROOF).unwrap();
+                // Verification key, proof and public witness of the shared BN254 Groth16 fixture, in the
+                // SnarkJS JSON format (see `fixtures/groth16/generator`).
+                let proof_json: ProofJson = serde_json::from_str(GROTH16_PROOF).unwrap();
                 let public_inputs_json = PublicInputsJson {
-                    values: serde_json::from_str(PUBLIC_INPUTS).unwrap(),
+                    values: serde_json::from_str(GROTH16_PUBLIC_INPUTS).unwrap(),
                 };
 
-                let verifying_key_json: VerifyingKeyJson = serde_json::from_str(VERIFICATION_KEY).unwrap();
+                let verifying_key_json: VerifyingKeyJson =
+                    serde_json::from_str(GROTH16_VERIFICATION_KEY).unwrap();
                 // Convert from the JSON data structure, with string encoded values.
                 let seal: Seal = proof_json.try_into().unwrap();
                 let public_inputs: Vec<Fr> = public_inputs_json.to_scalar().unwrap();
                 let verifying_key: VerifyingKey = verifying_key_json.verifying_key().unwrap();
-                
-                builder.write(&(seal, public_inputs, verifying_key)).unwrap();
-            },
+
+                builder
+                    .write(&(seal, public_inputs, verifying_key))
+                    .map_err(EvalError::serialize)?;
+            }
             ProgramId::ZKEmail => {
                 #[derive(Serialize, Deserialize, Debug, Clone)]
                 #[serde(rename_all = "camelCase")]
@@ -205,17 +254,40 @@ impl Risc0Evaluator {
                 const EMAIL_JSON: &[u8] = include_bytes!("../../fixtures/zk-email/email.json");
                 let email_input = serde_json::from_slice::<EmailInputs>(EMAIL_JSON).unwrap();
 
-                builder.write(&email_input).unwrap();
-            },
+                builder.write(&email_input).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Aggregation1 | ProgramId::Aggregation4 | ProgramId::Aggregation16 => {
+                builder
+                    .write(&vec![inner_image_id; inner_receipts.len()])
+                    .map_err(EvalError::serialize)?;
+                builder
+                    .write(
+                        &inner_receipts
+                            .iter()
+                            .map(|receipt| receipt.journal.bytes.clone())
+                            .collect::<Vec<_>>(),
+                    )
+                    .map_err(EvalError::serialize)?;
+                for receipt in inner_receipts.iter() {
+                    builder.add_assumption(receipt.clone());
+                }
+            }
             _ => {}
         }
-        let env = builder.build().unwrap();
+        let env = builder.build().map_err(EvalError::prove)?;
+
+        // A hashing program without an input arm would report throughput for bytes never hashed.
+        if hashed_bytes != hash_input_size_bytes(&args.program) {
+            return Err(EvalError::Prove(format!("{} was not given its hash input", args.program)));
+        }
 
         // Compute some statistics.
-        let mut exec = ExecutorImpl::from_elf(env, &elf).unwrap();
-        let session = exec.run().unwrap();
+        let mut exec = ExecutorImpl::from_elf(env, &elf).map_err(EvalError::prove)?;
+        let session = run_guest(&mut exec)?;
         let cycles = session.user_cycles;
+        let paging_cycles = session.paging_cycles;
         println!("cycles: {}", cycles);
+        println!("paging cycles: {}", paging_cycles);
 
         // Setup the prover.
         let mut builder = ExecutorEnv::builder();
@@ -269,30 +341,34 @@ impl Risc0Evaluator {
             ProgramId::Fibonacci400m => {
                 builder.write::<u32>(&30000000);
             }
-            ProgramId::Sha256100kb => {
-                builder.write(&vec![0u8; 102400]);
-            }
-            ProgramId::Sha256300kb => {
-                builder.write(&vec![0u8; 102400 * 3]);
-            }
-            ProgramId::Sha2561mb => {
-                builder.write(&vec![0u8; 1048576]);
-            }
-            ProgramId::Sha2563mb => {
-                builder.write(&vec![0u8; 1048576 * 3]);
-            }
-            ProgramId::Keccak256100kb => {
-                builder.write(&vec![0u8; 102400]);
+            ProgramId::Mulmod256 | ProgramId::Mulmod256Baseline => {
+                let (input, _) = mulmod256_input(args).unwrap();
+                builder.write(&input).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Modexp2048
+            | ProgramId::Modexp2048Baseline
+            | ProgramId::Modexp4096
+            | ProgramId::Modexp4096Baseline => {
+                let (input, _) = modexp_input(args).unwrap();
+                builder.write(&input).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Bn254Pairing
+            | ProgramId::Bn254PairingBaseline
+            | ProgramId::Bls12381Pairing
+            | ProgramId::Bls12381PairingBaseline => {
+                builder.write(&pairing_scalars(args).unwrap()).map_err(EvalError::serialize)?;
+            }
+            _ if memory_input(&args.program).is_some() => {
+                let (workload, ops) = memory_input(&args.program).unwrap();
+                builder.write(&workload).map_err(EvalError::serialize)?;
+                builder.write(&ops).map_err(EvalError::serialize)?;
+                builder.write(&args.seed).map_err(EvalError::serialize)?;
+            }
+            _ if hash_input_size_bytes(&args.program).is_some() => {
+                let (message_size, data) = hash_input(args).unwrap();
+                builder.write(&message_size).map_err(EvalError::serialize)?;
+                builder.write(&data).map_err(EvalError::serialize)?;
             }
-            ProgramId::Keccak256300kb => {
-                builder.write(&vec![0u8; 102400 * 3]);
-            }
-            ProgramId::Keccak2561mb => {
-                builder.write(&vec![0u8; 1048576]);
-            }
-            ProgramId::Keccak2563mb => {
-                builder.write(&vec![0u8; 1048576 * 3]);
-            },
             ProgramId::Rsp20526626 => {
                 let input = include_bytes!("../../fixtures/20526626.bin");
                 builder.write(&input.to_vec());
@@ -332,42 +408,50 @@ impl Risc0Evaluator {
             ProgramId::Rsp20528712 => {
                 let input = include_bytes!("../../fixtures/20528712.bin");
                 builder.write(&input.to_vec());
-            },
-            ProgramId::ECDSAVerify => {
-                builder.write(&rand_ecdsa_signature());
-            },
-            ProgramId::EDDSAVerify => {
-                let times: u8 = 100;
-                builder.write(&times);
-
-                for _ in 0..times {
-                    builder.write(&rand_eddsa_signature());
-                }
-            },
-            ProgramId::Helios => {
-                let input = include_bytes!("../../fixtures/helios/proof_inputs.cbor");
+            }
+            ProgramId::ECDSAVerify
+            | ProgramId::EDDSAVerify
+            | ProgramId::P256Verify
+            | ProgramId::SchnorrVerify
+            | ProgramId::BLSAggregateVerify => {
+                builder.write(&signature_batch(args).unwrap()).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Helios0 | ProgramId::Helios1 | ProgramId::Helios4 => {
+                let (input, _) = helios_fixture(&args.program).unwrap();
+                builder.write(&input.to_vec()).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Tendermint | ProgramId::TendermintSkipping => {
+                let (input, trusted, untrusted) = tendermint_input(args)?;
+                builder.write(&input).map_err(EvalError::serialize)?;
+                builder.write(&trusted).map_err(EvalError::serialize)?;
+                builder.write(&untrusted).map_err(EvalError::serialize)?;
+            }
+            ProgramId::SSZWithdrawals1x
+            | ProgramId::SSZWithdrawals10x
+            | ProgramId::SSZWithdrawals100x => {
+                let input = include_bytes!("../../fixtures/ssz-withdrawals/witness.bin");
+                builder.write(&ssz_withdrawals_params(&args.program).unwrap());
                 builder.write(&input.to_vec());
-            },
-            ProgramId::Groth16ProofVerify => { 
-                const PROOF: &str = include_str!("../../fixtures/risc0/proof.json");
-                const PUBLIC_INPUTS: &str = include_str!("../../fixtures/risc0/public.json");
-                const VERIFICATION_KEY: &str = include_str!("../../fixtures/risc0/verification_key.json");
-
-                // Verification_key, proof and public witness generated by SnarkJS using Groth16 over BN254
-                // (https://docs.circom.io/getting-started/proving-circuits/)
-                let proof_json: ProofJson = serde_json::from_str(PROOF).unwrap();
+            }
+            ProgramId::Groth16ProofVerify => {
+                // Verification key, proof and public witness of the shared BN254 Groth16 fixture, in the
+                // SnarkJS JSON format (see `fixtures/groth16/generator`).
+                let proof_json: ProofJson = serde_json::from_str(GROTH16_PROOF).unwrap();
                 let public_inputs_json = PublicInputsJson {
-                    values: serde_json::from_str(PUBLIC_INPUTS).unwrap(),
+                    values: serde_json::from_str(GROTH16_PUBLIC_INPUTS).unwrap(),
                 };
 
-                let verifying_key_json: VerifyingKeyJson = serde_json::from_str(VERIFICATION_KEY).unwrap();
+                let verifying_key_json: VerifyingKeyJson =
+                    serde_json::from_str(GROTH16_VERIFICATION_KEY).unwrap();
                 // Convert from the JSON data structure, with string encoded values.
                 let seal: Seal = proof_json.try_into().unwrap();
                 let public_inputs: Vec<Fr> = public_inputs_json.to_scalar().unwrap();
                 let verifying_key: VerifyingKey = verifying_key_json.verifying_key().unwrap();
-                
-                builder.write(&(seal, public_inputs, verifying_key)).unwrap();
-            },
+
+                builder
+                    .write(&(seal, public_inputs, verifying_key))
+                    .map_err(EvalError::serialize)?;
+            }
             ProgramId::ZKEmail => {
                 #[derive(Serialize, Deserialize, Debug, Clone)]
                 #[serde(rename_all = "camelCase")]
@@ -382,26 +466,46 @@ impl Risc0Evaluator {
                 const EMAIL_JSON: &[u8] = include_bytes!("../../fixtures/zk-email/email.json");
                 let email_input = serde_json::from_slice::<EmailInputs>(EMAIL_JSON).unwrap();
 
-                builder.write(&email_input).unwrap();
-            },
+                builder.write(&email_input).map_err(EvalError::serialize)?;
+            }
+            ProgramId::Aggregation1 | ProgramId::Aggregation4 | ProgramId::Aggregation16 => {
+                builder
+                    .write(&vec![inner_image_id; inner_receipts.len()])
+                    .map_err(EvalError::serialize)?;
+                builder
+                    .write(
+                        &inner_receipts
+                            .iter()
+                            .map(|receipt| receipt.journal.bytes.clone())
+                            .collect::<Vec<_>>(),
+                    )
+                    .map_err(EvalError::serialize)?;
+                for receipt in inner_receipts.iter() {
+                    builder.add_assumption(receipt.clone());
+                }
+            }
             _ => {}
         }
-        let env = builder.build().unwrap();
-        let opts = ProverOpts::default();
-        let prover = get_prover_server(&opts).unwrap();
+        let env = builder.build().map_err(EvalError::prove)?;
+        let mut opts = ProverOpts::default();
+        opts.hashfn = hashfn.to_string();
+        if let Some(max_segment_po2) = args.risc0_max_segment_po2 {
+            opts = opts.with_max_segment_po2(max_segment_po2);
+        }
+        let prover = get_prover_server(&opts).map_err(EvalError::prove)?;
 
         // Generate the session.
-        let mut exec = ExecutorImpl::from_elf(env, &elf).unwrap();
-        let (session, execution_duration) = time_operation(|| exec.run().unwrap());
+        let mut exec = ExecutorImpl::from_elf(env, &elf).map_err(EvalError::prove)?;
+        let (session, execution_duration) = time_operation(|| run_guest(&mut exec));
+        let session = session?;
 
         // Generate the proof.
         let ctx = VerifierContext::default();
-        let (info, core_prove_duration) =
-            time_operation(|| prover.prove_session(&ctx, &session).unwrap());
+        let (info, core_prove_duration) = time_operation(|| prover.prove_session(&ctx, &session));
 
-        let receipt = info.receipt;
+        let receipt = info.map_err(EvalError::prove)?.receipt;
 
-        let composite_receipt = receipt.inner.composite().unwrap();
+        let composite_receipt = receipt.inner.composite().map_err(EvalError::prove)?;
         let num_segments = composite_receipt.segments.len();
 
         // Get the core proof size by summing across all segments.
@@ -411,39 +515,189 @@ impl Risc0Evaluator {
         }
 
         // Verify the core proof.
-        let ((), core_verify_duration) = time_operation(|| receipt.verify(image_id).unwrap());
+        let (verification, core_verify_duration) = time_operation(|| receipt.verify(image_id));
+        verification.map_err(EvalError::verify)?;
+
+        // Check the state committed by the Helios programs.
+        if let Some((_, expected)) = helios_fixture(&args.program) {
+            let outputs: HeliosOutputs = receipt.journal.decode().map_err(EvalError::verify)?;
+            if outputs != expected {
+                return Err(EvalError::Verify("helios committed an unexpected state".to_string()));
+            }
+        }
+
+        // Check that the hashing programs hashed every byte they were given.
+        if let Some(num_bytes) = hashed_bytes {
+            let (committed_bytes, _): (u64, [u8; 32]) =
+                receipt.journal.decode().map_err(EvalError::verify)?;
+            if committed_bytes != num_bytes {
+                return Err(EvalError::Verify(format!(
+                    "the guest hashed {} bytes instead of {}",
+                    committed_bytes, num_bytes
+                )));
+            }
+        }
+
+        // Check that the aggregation programs verified the inner proofs they were given.
+        if aggregation_size(&args.program).is_some() {
+            let outputs = inner_receipts
+                .iter()
+                .map(|receipt| receipt.journal.bytes.clone())
+                .collect::<Vec<_>>();
+            let expected =
+                (vec![inner_image_id; inner_receipts.len()], aggregation_outputs_digest(&outputs));
+            let committed: (Vec<[u32; 8]>, [u8; 32]) =
+                receipt.journal.decode().map_err(EvalError::verify)?;
+            if committed != expected {
+                return Err(EvalError::Verify(
+                    "the aggregation committed unexpected keys or outputs".to_string(),
+                ));
+            }
+        }
+
+        // Check the results committed by the big-integer and pairing programs.
+        if let Some((_, expected)) = mulmod256_input(args) {
+            let product: [u32; 8] = receipt.journal.decode().map_err(EvalError::verify)?;
+            if product != expected {
+                return Err(EvalError::Verify("mulmod256 committed a wrong product".to_string()));
+            }
+        }
+        if let Some((_, expected)) = modexp_input(args) {
+            let power: Vec<u8> = receipt.journal.decode().map_err(EvalError::verify)?;
+            if power != expected {
+                return Err(EvalError::Verify("modexp committed an unexpected power".to_string()));
+            }
+        }
+        if let Some(scalars) = pairing_scalars(args) {
+            let checks: u32 = receipt.journal.decode().map_err(EvalError::verify)?;
+            if checks as usize != scalars.len() {
+                return Err(EvalError::Verify(format!(
+                    "the guest made {} of {} pairing checks",
+                    checks,
+                    scalars.len()
+                )));
+            }
+        }
 
-        // Now compress the proof with recursion.
-        let (compressed_proof, compress_duration) =
-            time_operation(|| prover.compress(&ProverOpts::succinct(), &receipt).unwrap());
+        // Now compress the proof with recursion into the requested receipt kind.
+        //
+        // Rather than calling `prover.compress` directly, lift every segment receipt and then
+        // reduce the lifted receipts with a binary tree of joins, so that each phase can be timed.
+        let compress_opts = opts.clone().with_receipt_kind(receipt_kind);
+        let num_assumptions = composite_receipt.assumption_receipts.len();
+        let mut lift_duration = None;
+        let mut join_duration = None;
+        let mut resolve_duration = None;
+        let mut join_count = 0;
+        let mut join_depth = 0;
+        let (compressed_proof, compress_duration) = time_operation(|| {
+            if args.risc0_receipt_kind == Risc0ReceiptKind::Composite {
+                return Ok(receipt.clone());
+            }
+
+            // Lift: segment receipts -> succinct receipts.
+            let (lifted, tmp_lift_duration) = time_operation(|| {
+                composite_receipt
+                    .segments
+                    .iter()
+                    .map(|segment| prover.lift(segment).map_err(EvalError::prove))
+                    .collect::<Result<Vec<_>, _>>()
+            });
+            lift_duration = Some(tmp_lift_duration);
+
+            // Join: reduce the succinct receipts pairwise, one tree layer at a time.
+            let (joined, tmp_join_duration) = time_operation(|| -> Result<_, EvalError> {
+                let mut layer = lifted?;
+                while layer.len() > 1 {
+                    layer = layer
+                        .chunks(2)
+                        .map(|pair| match pair {
+                            [left, right] => {
+                                join_count += 1;
+                                prover.join(left, right).map_err(EvalError::prove)
+                            }
+                            [single] => Ok(single.clone()),
+                            _ => unreachable!(),
+                        })
+                        .collect::<Result<_, _>>()?;
+                    join_depth += 1;
+                }
+                Ok(layer.pop().unwrap())
+            });
+            join_duration = Some(tmp_join_duration);
+
+            // Resolve: discharge every assumption (e.g. an aggregated inner proof) by folding its
+            // receipt into the joined receipt.
+            let (resolved, tmp_resolve_duration) = time_operation(|| {
+                composite_receipt.assumption_receipts.iter().try_fold(
+                    joined?,
+                    |conditional, assumption| match assumption {
+                        InnerAssumptionReceipt::Succinct(assumption) => {
+                            prover.resolve(&conditional, assumption).map_err(EvalError::prove)
+                        }
+                        _ => Err(EvalError::Prove(
+                            "only succinct assumption receipts can be resolved".to_string(),
+                        )),
+                    },
+                )
+            });
+            resolve_duration = Some(tmp_resolve_duration);
+
+            let succinct_receipt =
+                Receipt::new(InnerReceipt::Succinct(resolved?), receipt.journal.bytes.clone());
+            prover.compress(&compress_opts, &succinct_receipt).map_err(EvalError::prove)
+        });
+        let compressed_proof = compressed_proof?;
+        if let (Some(lift_duration), Some(join_duration), Some(resolve_duration)) =
+            (lift_duration, join_duration, resolve_duration)
+        {
+            println!(
+                "lifted {} segments in {:?}, joined {} times (depth {}) in {:?} and resolved {} assumptions in {:?}",
+                num_segments, lift_duration, join_count, join_depth, join_duration, num_assumptions, resolve_duration
+            );
+        }
 
         // Verify the recursive proof
-        let ((), recursive_verify_duration) =
-            time_operation(|| compressed_proof.verify(image_id).unwrap());
+        let (verification, recursive_verify_duration) =
+            time_operation(|| compressed_proof.verify(image_id));
+        verification.map_err(EvalError::verify)?;
 
-        let succinct_receipt = compressed_proof.inner.succinct().unwrap();
+        // Get the recursive proof size.
+        let recursive_proof_size = match &compressed_proof.inner {
+            InnerReceipt::Composite(composite) => {
+                composite.segments.iter().map(|segment| segment.seal.len() * 4).sum()
+            }
+            InnerReceipt::Succinct(succinct) => succinct.seal.len() * 4,
+            InnerReceipt::Groth16(groth16) => groth16.seal.len(),
+            _ => 0,
+        };
 
         let mut shrink_prove_duration = std::time::Duration::from_secs(0);
         let mut wrap_prove_duration = std::time::Duration::from_secs(0);
         let mut groth16_prove_duration = std::time::Duration::from_secs(0);
 
-        if args.groth16 {
+        if args.groth16 && args.risc0_receipt_kind == Risc0ReceiptKind::Succinct {
             // Bn254 wrapping duration
-            let (bn254_proof, tmp_bn254_compress_duration) = time_operation(|| {
-                prover.identity_p254(&compressed_proof.inner.succinct().unwrap()).unwrap()
-            });
-            let seal_bytes = bn254_proof.get_seal_bytes();
+            let succinct_proof = compressed_proof.inner.succinct().map_err(EvalError::prove)?;
+            let (bn254_proof, tmp_bn254_compress_duration) =
+                time_operation(|| prover.identity_p254(succinct_proof));
+            let seal_bytes = bn254_proof.map_err(EvalError::prove)?.get_seal_bytes();
             println!("Running groth16 wrapper");
-            let (_groth16_proof, tmp_groth16_duration) =
-                time_operation(|| risc0_zkvm::stark_to_snark(&seal_bytes).unwrap());
+            let (groth16_proof, tmp_groth16_duration) =
+                time_operation(|| risc0_zkvm::stark_to_snark(&seal_bytes));
+            groth16_proof.map_err(EvalError::prove)?;
 
             println!("Done running groth16");
             wrap_prove_duration = tmp_bn254_compress_duration;
             groth16_prove_duration = tmp_groth16_duration;
         }
 
-        // Get the recursive proof size.
-        let recursive_proof_size = succinct_receipt.seal.len() * 4;
+        let (lift_count, resolve_count) = if args.risc0_receipt_kind == Risc0ReceiptKind::Composite
+        {
+            (0, 0)
+        } else {
+            (num_segments, num_assumptions)
+        };
         let prove_duration = core_prove_duration + compress_duration;
 
         let core_khz = cycles as f64 / core_prove_duration.as_secs_f64() / 1_000.0;
@@ -458,6 +712,7 @@ impl Risc0Evaluator {
             shard_size: args.shard_size,
             shards: num_segments,
             cycles: cycles as u64,
+            paging_cycles: Some(paging_cycles),
             speed: (cycles as f64) / prove_duration.as_secs_f64(),
             execution_duration: execution_duration.as_secs_f64(),
             prove_duration: prove_duration.as_secs_f64(),
@@ -470,21 +725,76 @@ impl Risc0Evaluator {
             compress_proof_size: recursive_proof_size,
             overall_khz,
             gas: gas_amount(&args.program),
-            hashes_per_second: hashes_per_second(&args.program, prove_duration),
-            hash_bytes_per_second: hash_bytes_per_second(&args.program, prove_duration),
+            hashes_per_second: hashes_per_second(args, hashed_bytes, prove_duration),
+            hash_bytes_per_second: hash_bytes_per_second(hashed_bytes, prove_duration),
             shrink_prove_duration: shrink_prove_duration.as_secs_f64(),
             wrap_prove_duration: wrap_prove_duration.as_secs_f64(),
             groth16_prove_duration: groth16_prove_duration.as_secs_f64(),
             plonk_prove_duration: 0.0,
+            receipt_kind: Some(args.risc0_receipt_kind.to_string()),
+            max_segment_po2: args.risc0_max_segment_po2,
+            keccak_accel: Some(args.risc0_keccak),
+            bigint2_accel: Some(args.risc0_bigint2),
+            compress_lift_duration: lift_duration.map(|duration| duration.as_secs_f64()),
+            compress_lift_count: Some(lift_count),
+            compress_join_duration: join_duration.map(|duration| duration.as_secs_f64()),
+            compress_resolve_duration: resolve_duration.map(|duration| duration.as_secs_f64()),
+            compress_join_count: Some(join_count),
+            compress_tree_depth: Some(join_depth),
+            recursion_programs: Some(lift_count + join_count + resolve_count),
+            deferred_proofs: num_assumptions,
+            inner_prove_duration: inner_prove_duration.map(|duration| duration.as_secs_f64()),
+            aggregation_prove_duration: inner_prove_duration
+                .map(|duration| (duration + prove_duration).as_secs_f64()),
+            tendermint_encoding: tendermint_encoding(args),
+            signature_count: signature_count(args),
+            signers: signers(args),
+            hash_message_size: hash_message_size(args),
+            hash_bytes: hash_bytes(args),
+            seed: args.seed,
+            precompiles: args.precompiles.to_string(),
+            baseline_cycles: None,
+            baseline_prove_duration: None,
+            speedup: None,
+            elf_source: args.elf_source.to_string(),
+            elf_sha256: elf_sha256(&elf),
+            elf_size: elf.len() as u64,
+            elf_text_size: elf_text_size(&elf).unwrap_or_default(),
+            vkey: image_id.to_string(),
+            guest_opt_level: guest_profile(args).map(|profile| profile.opt_level.to_string()),
+            guest_lto: guest_profile(args)
+                .and_then(|profile| profile.lto.as_ref())
+                .map(|lto| lto.to_string()),
+            guest_codegen_units: guest_profile(args).map(|profile| profile.codegen_units),
+            guest_panic: guest_profile(args).map(|profile| profile.panic.to_string()),
+            ..Default::default()
         };
 
         println!("report: {:#?}", report);
 
-        report
+        Ok(report)
     }
 
     #[cfg(not(feature = "risc0"))]
-    pub fn eval(_args: &EvalArgs) -> PerformanceReport {
-        panic!("RISC0 feature is not enabled. Please compile with --features risc0");
+    pub fn eval(_args: &EvalArgs) -> Result<PerformanceReport, EvalError> {
+        Err(EvalError::Prove(
+            "RISC0 feature is not enabled. Please compile with --features risc0".to_string(),
+        ))
+    }
+}
+
+/// Runs the guest to completion, turning a guest that panics or halts with a non-zero exit code
+/// into an error.
+#[cfg(feature = "risc0")]
+fn run_guest(exec: &mut ExecutorImpl) -> Result<Session, EvalError> {
+    let session = exec
+        .run()
+        .map_err(|err| EvalError::GuestPanic { message: err.to_string(), exit_code: None })?;
+    match session.exit_code {
+        ExitCode::Halted(exit_code) if exit_code != 0 => Err(EvalError::GuestPanic {
+            message: format!("the guest halted with exit code {}", exit_code),
+            exit_code: Some(exit_code),
+        }),
+        _ => Ok(session),
     }
 }
@@ -2,16 +2,23 @@ use core::time;
 use std::fs;
 
 use crate::{
-    utils::{gas_amount, get_elf, hash_bytes_per_second, hashes_per_second, rand_ecdsa_signature, rand_eddsa_signature, time_operation},
-    EvalArgs, PerformanceReport, ProgramId,
+    utils::{
+        aggregation_inner_elf, aggregation_outputs_digest, aggregation_size, elf_sha256,
+        elf_text_size, gas_amount, get_elf, groth16_fixture_bytes, guest_profile, hash_bytes,
+        hash_bytes_per_second, hash_input, hash_input_size_bytes, hash_message_size,
+        hashes_per_second, helios_fixture, memory_input, modexp_input, mulmod256_input,
+        pairing_scalars, signature_batch, signature_count, signers, ssz_withdrawals_params,
+        tendermint_encoding, tendermint_input, time_operation, HeliosOutputs,
+    },
+    EvalArgs, EvalError, PerformanceReport, ProgramId, ProverId,
 };
 
-use sp1_sdk::Prover;
-use sp1_core_executor::SP1Context;
-use sp1_prover::build::try_build_groth16_bn254_artifacts_dev;
+use sp1_core_executor::{ExecutionError, SP1Context};
 use sp1_core_machine::io::SP1Stdin;
-use sp1_prover::{components::CpuProverComponents, utils::get_cycles, SP1Prover};
-use sp1_prover::HashableKey;
+use sp1_prover::{
+    build::try_build_groth16_bn254_artifacts_dev, components::CpuProverComponents,
+    utils::get_cycles, HashableKey, SP1Prover,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -24,10 +31,38 @@ use sp1_stark::SP1ProverOpts;
 pub struct SP1Evaluator;
 
 impl SP1Evaluator {
-    pub fn eval(args: &EvalArgs) -> PerformanceReport { 
+    pub fn eval(args: &EvalArgs) -> Result<PerformanceReport, EvalError> {
+        let prover = SP1Prover::<CpuProverComponents>::new();
+
+        // why did i do this, i do not remember.
+        //
+        // #[cfg(feature = "cuda")]
+        // {
+        //     prover.single_shard_programs = None;
+        // }
+
+        #[cfg(feature = "cuda")]
+        let server = SP1CudaProver::new().map_err(EvalError::prove)?;
+
+        // Setup the prover opionts.
+        #[cfg(not(feature = "cuda"))]
+        let mut opts = SP1ProverOpts::auto();
+
+        // Limit the workers that generate the traces of the core and recursion shards to
+        // `--threads`, on top of the rayon pool they prove with.
+        #[cfg(not(feature = "cuda"))]
+        if let Some(threads) = args.threads {
+            opts.core_opts.trace_gen_workers = opts.core_opts.trace_gen_workers.min(threads);
+            opts.recursion_opts.trace_gen_workers =
+                opts.recursion_opts.trace_gen_workers.min(threads);
+        }
+
         // Get stdin.
         let mut stdin = SP1Stdin::new();
-        match args.program { 
+        let mut inner_prove_duration = None;
+        let mut aggregation_commit = None;
+        let mut hashed_bytes = None;
+        match args.program {
             ProgramId::Loop10k => {
                 stdin.write::<usize>(&2500);
             }
@@ -85,35 +120,34 @@ impl SP1Evaluator {
             ProgramId::Fibonacci4b => {
                 stdin.write::<u32>(&300000000);
             }
-            ProgramId::Sha256100kb => {
-                stdin.write(&vec![0u8; 102400]);
-            }
-            ProgramId::Sha256300kb => {
-                stdin.write(&vec![0u8; 102400 * 3]);
-            }
-            ProgramId::Sha2561mb => {
-                stdin.write(&vec![0u8; 1048576]);
-            }
-            ProgramId::Sha2563mb => {
-                stdin.write(&vec![0u8; 1048576 * 3]);
-            }
-            ProgramId::Sha25610mb => {
-                stdin.write(&vec![0u8; 1048576 * 10]);
-            }
-            ProgramId::Keccak256100kb => {
-                stdin.write(&vec![0u8; 102400]);
-            }
-            ProgramId::Keccak256300kb => {
-                stdin.write(&vec![0u8; 102400 * 3]);
-            }
-            ProgramId::Keccak2561mb => {
-                stdin.write(&vec![0u8; 1048576]);
-            }
-            ProgramId::Keccak2563mb => {
-                stdin.write(&vec![0u8; 1048576 * 3]);
-            }
-            ProgramId::Keccak25610mb => {
-                stdin.write(&vec![0u8; 1048576 * 10]);
+            ProgramId::Mulmod256 | ProgramId::Mulmod256Baseline => {
+                let (input, _) = mulmod256_input(args).unwrap();
+                stdin.write(&input);
+            }
+            ProgramId::Modexp2048
+            | ProgramId::Modexp2048Baseline
+            | ProgramId::Modexp4096
+            | ProgramId::Modexp4096Baseline => {
+                let (input, _) = modexp_input(args).unwrap();
+                stdin.write(&input);
+            }
+            ProgramId::Bn254Pairing
+            | ProgramId::Bn254PairingBaseline
+            | ProgramId::Bls12381Pairing
+            | ProgramId::Bls12381PairingBaseline => {
+                stdin.write(&pairing_scalars(args).unwrap());
+            }
+            _ if memory_input(&args.program).is_some() => {
+                let (workload, ops) = memory_input(&args.program).unwrap();
+                stdin.write(&workload);
+                stdin.write(&ops);
+                stdin.write(&args.seed);
+            }
+            _ if hash_input_size_bytes(&args.program).is_some() => {
+                let (message_size, data) = hash_input(args).unwrap();
+                hashed_bytes = Some(data.len() as u64);
+                stdin.write(&message_size);
+                stdin.write(&data);
             }
             ProgramId::Rsp20526626 => {
                 let input = include_bytes!("../../fixtures/20526626.bin");
@@ -154,39 +188,36 @@ impl SP1Evaluator {
             ProgramId::Rsp20528712 => {
                 let input = include_bytes!("../../fixtures/20528712.bin");
                 stdin.write_vec(input.to_vec());
-            },
-            ProgramId::ECDSAVerify => {
-                stdin.write(&rand_ecdsa_signature());
-            },
-            ProgramId::EDDSAVerify => {
-                let times: u8 = 100;
-                for _ in 0..times {
-                    stdin.write(&rand_eddsa_signature());
-                }
-            },
-            ProgramId::Helios => {
-                let input = include_bytes!("../../fixtures/helios/proof_inputs.cbor");
+            }
+            ProgramId::ECDSAVerify
+            | ProgramId::EDDSAVerify
+            | ProgramId::P256Verify
+            | ProgramId::SchnorrVerify
+            | ProgramId::BLSAggregateVerify => {
+                stdin.write(&signature_batch(args).unwrap());
+            }
+            ProgramId::Helios0 | ProgramId::Helios1 | ProgramId::Helios4 => {
+                let (input, _) = helios_fixture(&args.program).unwrap();
                 stdin.write_vec(input.to_vec());
-            },
+            }
+            ProgramId::Tendermint | ProgramId::TendermintSkipping => {
+                let (input, trusted, untrusted) = tendermint_input(args)?;
+                stdin.write(&input);
+                stdin.write(&trusted);
+                stdin.write(&untrusted);
+            }
+            ProgramId::SSZWithdrawals1x
+            | ProgramId::SSZWithdrawals10x
+            | ProgramId::SSZWithdrawals100x => {
+                let input = include_bytes!("../../fixtures/ssz-withdrawals/witness.bin");
+                stdin.write(&ssz_withdrawals_params(&args.program).unwrap());
+                stdin.write_vec(input.to_vec());
+            }
             ProgramId::Groth16ProofVerify => {
-                let current_dir = std::env::current_dir().expect("Failed to get current working directory");
-
-                let elf_path = 
-                    current_dir.join("programs/fibonacci/target/riscv32im-succinct-zkvm-elf/release/fibonacci"); 
-
-                let elf = fs::read(elf_path).unwrap();
-
-                let mut input = SP1Stdin::new();
-                input.write(&20_u32);
-
-                let client = sp1_sdk::ProverClient::builder().cpu().build();
-                let (pk, vk) = client.setup(&elf);
-                let proof = client.prove(&pk, &input).groth16().run().unwrap();
-                
-                stdin.write_vec(proof.bytes());
-                stdin.write_vec(proof.public_values.to_vec());
-                stdin.write(&vk.bytes32());
-            },
+                // The shared BN254 Groth16 fixture (see `fixtures/groth16/generator`), decoded on
+                // the host so the guest only runs the verifier.
+                stdin.write(&groth16_fixture_bytes());
+            }
             ProgramId::ZKEmail => {
                 #[derive(Serialize, Deserialize, Debug, Clone)]
                 #[serde(rename_all = "camelCase")]
@@ -202,12 +233,71 @@ impl SP1Evaluator {
                 let email_input = serde_json::from_slice::<EmailInputs>(EMAIL_JSON).unwrap();
 
                 stdin.write(&email_input);
-            },
+            }
+            ProgramId::Aggregation1 | ProgramId::Aggregation4 | ProgramId::Aggregation16 => {
+                let inner_elf = aggregation_inner_elf(&ProverId::SP1)?;
+
+                let mut input = SP1Stdin::new();
+                input.write::<u32>(&1500);
+
+                // Prove the inner programs up front with the prover of the outer proof; their
+                // compressed proofs are verified by the outer program and passed to compression as
+                // deferred proofs.
+                let num_proofs = aggregation_size(&args.program).unwrap();
+                #[cfg(not(feature = "cuda"))]
+                let (_, inner_pk_d, inner_program, inner_vk) = prover.setup(&inner_elf);
+                #[cfg(feature = "cuda")]
+                let (_, inner_vk) = server.setup(&inner_elf).map_err(EvalError::prove)?;
+                let (proofs, tmp_inner_prove_duration) = time_operation(|| {
+                    (0..num_proofs)
+                        .map(|_| {
+                            #[cfg(not(feature = "cuda"))]
+                            let core_proof = prover.prove_core(
+                                &inner_pk_d,
+                                inner_program.clone(),
+                                &input,
+                                opts,
+                                SP1Context::default(),
+                            );
+                            #[cfg(feature = "cuda")]
+                            let core_proof = server.prove_core(&input);
+
+                            let core_proof = core_proof.map_err(EvalError::prove)?;
+                            let public_values = core_proof.public_values.to_vec();
+
+                            #[cfg(not(feature = "cuda"))]
+                            let proof = prover.compress(&inner_vk, core_proof, vec![], opts);
+                            #[cfg(feature = "cuda")]
+                            let proof = server.compress(&inner_vk, core_proof, vec![]);
+
+                            Ok((proof.map_err(EvalError::prove)?, public_values))
+                        })
+                        .collect::<Result<Vec<_>, EvalError>>()
+                });
+                let proofs = proofs?;
+                inner_prove_duration = Some(tmp_inner_prove_duration);
+
+                let keys = vec![inner_vk.hash_u32(); num_proofs];
+                let outputs = proofs
+                    .iter()
+                    .map(|(_, public_values)| public_values.clone())
+                    .collect::<Vec<_>>();
+                stdin.write(&keys);
+                stdin.write(&outputs);
+                aggregation_commit = Some((keys, aggregation_outputs_digest(&outputs)));
+                for (proof, _) in proofs {
+                    stdin.write_proof(proof, inner_vk.vk.clone());
+                }
+            }
             _ => {}
         }
 
-        let elf_path = get_elf(args);
-        let elf = fs::read(elf_path).unwrap();
+        // A hashing program without an input arm would report throughput for bytes never hashed.
+        if hashed_bytes != hash_input_size_bytes(&args.program) {
+            return Err(EvalError::Prove(format!("{} was not given its hash input", args.program)));
+        }
+
+        let elf = get_elf(args)?;
         if std::env::var("SAVE").unwrap_or_default() == "1" {
             let stdin_bytes = bincode::serialize(&stdin).unwrap();
             fs::write("stdin.bin", &stdin_bytes).unwrap();
@@ -216,7 +306,7 @@ impl SP1Evaluator {
                     "s3",
                     "cp",
                     &format!("stdin.bin"),
-                    &format!("s3://sp1-testing-suite/v4/{}/stdin.bin", args.program.to_string())
+                    &format!("s3://sp1-testing-suite/v4/{}/stdin.bin", args.program.to_string()),
                 ])
                 .status()
                 .expect("Failed to upload stdin.bin to S3");
@@ -224,105 +314,162 @@ impl SP1Evaluator {
             fs::write("program.bin", &elf).unwrap();
             std::process::Command::new("aws")
                 .args(&[
-                    "s3", 
+                    "s3",
                     "cp",
                     &format!("program.bin"),
-                    &format!("s3://sp1-testing-suite/v4/{}/program.bin", args.program.to_string())
+                    &format!("s3://sp1-testing-suite/v4/{}/program.bin", args.program.to_string()),
                 ])
                 .status()
                 .expect("Failed to upload program.bin to S3");
             std::process::exit(0);
         }
 
-        // Get the elf. 
-        let cycles = get_cycles(&elf, &stdin); 
-        println!("cycles: {}", cycles);
-
-        let prover = SP1Prover::<CpuProverComponents>::new();
-
-        // why did i do this, i do not remember.
-        //
-        // #[cfg(feature = "cuda")]
-        // {
-        //     prover.single_shard_programs = None;
-        // }
-
-        #[cfg(feature = "cuda")]
-        let server = SP1CudaProver::new().expect("Failed to initialize CUDA prover");
-
         // Setup the program.
         #[cfg(not(feature = "cuda"))]
         let (_, pk_d, program, vk) = prover.setup(&elf);
 
         #[cfg(feature = "cuda")]
-        let (pk, vk) = server.setup(&elf).unwrap();
+        let (pk, vk) = server.setup(&elf).map_err(EvalError::prove)?;
 
         // Execute the program.
         let context = SP1Context::default();
-        let ((pv, _), execution_duration) =
-            time_operation(|| prover.execute(&elf, &stdin, context.clone()).unwrap());
+        let (execution, execution_duration) =
+            time_operation(|| prover.execute(&elf, &stdin, context.clone()));
+        let (pv, _) = execution.map_err(|err| {
+            let exit_code = match err {
+                ExecutionError::HaltWithNonZeroExitCode(exit_code) => Some(exit_code),
+                _ => None,
+            };
+            EvalError::GuestPanic { message: err.to_string(), exit_code }
+        })?;
+
+        // Count the cycles, now that the guest is known to run to completion.
+        let cycles = get_cycles(&elf, &stdin);
+        println!("cycles: {}", cycles);
 
-        // Setup the prover opionts.
-        #[cfg(not(feature = "cuda"))]
-        let opts = SP1ProverOpts::auto();
+        // Check the state committed by the Helios programs.
+        if let Some((_, expected)) = helios_fixture(&args.program) {
+            let outputs: HeliosOutputs =
+                bincode::deserialize(&pv.to_vec()).map_err(EvalError::verify)?;
+            if outputs != expected {
+                return Err(EvalError::Verify("helios committed an unexpected state".to_string()));
+            }
+        }
+
+        // Check that the hashing programs hashed every byte they were given.
+        if let Some(num_bytes) = hashed_bytes {
+            let (committed_bytes, _): (u64, [u8; 32]) =
+                bincode::deserialize(&pv.to_vec()).map_err(EvalError::verify)?;
+            if committed_bytes != num_bytes {
+                return Err(EvalError::Verify(format!(
+                    "the guest hashed {} bytes instead of {}",
+                    committed_bytes, num_bytes
+                )));
+            }
+        }
+
+        // Check that the aggregation programs verified the inner proofs they were given.
+        if let Some(expected) = aggregation_commit {
+            let committed: (Vec<[u32; 8]>, [u8; 32]) =
+                bincode::deserialize(&pv.to_vec()).map_err(EvalError::verify)?;
+            if committed != expected {
+                return Err(EvalError::Verify(
+                    "the aggregation committed unexpected keys or outputs".to_string(),
+                ));
+            }
+        }
+
+        // Check the results committed by the big-integer and pairing programs.
+        if let Some((_, expected)) = mulmod256_input(args) {
+            let product: [u32; 8] =
+                bincode::deserialize(&pv.to_vec()).map_err(EvalError::verify)?;
+            if product != expected {
+                return Err(EvalError::Verify("mulmod256 committed a wrong product".to_string()));
+            }
+        }
+        if let Some((_, expected)) = modexp_input(args) {
+            let power: Vec<u8> = bincode::deserialize(&pv.to_vec()).map_err(EvalError::verify)?;
+            if power != expected {
+                return Err(EvalError::Verify("modexp committed an unexpected power".to_string()));
+            }
+        }
+        if let Some(scalars) = pairing_scalars(args) {
+            let checks: u32 = bincode::deserialize(&pv.to_vec()).map_err(EvalError::verify)?;
+            if checks as usize != scalars.len() {
+                return Err(EvalError::Verify(format!(
+                    "the guest made {} of {} pairing checks",
+                    checks,
+                    scalars.len()
+                )));
+            }
+        }
 
         // Generate the core proof (CPU).
         #[cfg(not(feature = "cuda"))]
         let (core_proof, prove_core_duration) =
-            time_operation(|| prover.prove_core(&pk_d, program, &stdin, opts, context).unwrap());
+            time_operation(|| prover.prove_core(&pk_d, program, &stdin, opts, context));
 
         // Generate the core proof (CUDA).
         #[cfg(feature = "cuda")]
-        let (core_proof, prove_core_duration) =
-            time_operation(|| server.prove_core(&stdin).unwrap());
+        let (core_proof, prove_core_duration) = time_operation(|| server.prove_core(&stdin));
 
+        let core_proof = core_proof.map_err(EvalError::prove)?;
         let num_shards = core_proof.proof.0.len();
 
         // Verify the proof.
-        let core_bytes = bincode::serialize(&core_proof).unwrap();
-        let (_, verify_core_duration) = time_operation(|| {
-            prover.verify(&core_proof.proof, &vk).expect("Proof verification failed")
-        });
+        let core_bytes = bincode::serialize(&core_proof).map_err(EvalError::serialize)?;
+        let (verification, verify_core_duration) =
+            time_operation(|| prover.verify(&core_proof.proof, &vk));
+        verification.map_err(EvalError::verify)?;
+
+        // Any proofs written to stdin are verified as deferred proofs during compression.
+        let deferred_proofs =
+            stdin.proofs.iter().map(|(proof, _)| proof.clone()).collect::<Vec<_>>();
+        let num_deferred_proofs = deferred_proofs.len();
 
         #[cfg(not(feature = "cuda"))]
         let (compress_proof, compress_duration) =
-            time_operation(|| prover.compress(&vk, core_proof, vec![], opts).unwrap());
+            time_operation(|| prover.compress(&vk, core_proof, deferred_proofs, opts));
 
         #[cfg(feature = "cuda")]
         let (compress_proof, compress_duration) =
-            time_operation(|| server.compress(&vk, core_proof, vec![]).unwrap());
+            time_operation(|| server.compress(&vk, core_proof, deferred_proofs));
 
-        let compress_bytes = bincode::serialize(&compress_proof).unwrap();
+        let compress_proof = compress_proof.map_err(EvalError::prove)?;
+        let compress_bytes = bincode::serialize(&compress_proof).map_err(EvalError::serialize)?;
         println!("recursive proof size: {}", compress_bytes.len());
 
-        let (_, verify_compress_duration) = time_operation(|| {
-            prover.verify_compressed(&compress_proof, &vk).expect("Proof verification failed")
-        });
+        let (verification, verify_compress_duration) =
+            time_operation(|| prover.verify_compressed(&compress_proof, &vk));
+        verification.map_err(EvalError::verify)?;
 
         let mut shrink_prove_duration = time::Duration::from_secs(0);
         let mut wrap_prove_duration = time::Duration::from_secs(0);
         let mut wrap_verify_duration = time::Duration::from_secs(0);
         let mut groth16_prove_duration = time::Duration::from_secs(0);
-        if args.groth16 { 
+        if args.groth16 {
             #[cfg(not(feature = "cuda"))]
             let (shrink_proof, tmp_shrink_prove_duration) =
-                time_operation(|| prover.shrink(compress_proof.clone(), opts).unwrap());
-    
+                time_operation(|| prover.shrink(compress_proof.clone(), opts));
+
             #[cfg(feature = "cuda")]
             let (shrink_proof, tmp_shrink_prove_duration) =
-                time_operation(|| server.shrink(compress_proof.clone()).unwrap());
-    
+                time_operation(|| server.shrink(compress_proof.clone()));
+
+            let shrink_proof = shrink_proof.map_err(EvalError::prove)?;
             shrink_prove_duration = tmp_shrink_prove_duration;
-            let shrink_bytes = bincode::serialize(&shrink_proof).unwrap();
-            prover.verify_shrink(&shrink_proof, &vk).expect("Proof verification failed");
+            let shrink_bytes = bincode::serialize(&shrink_proof).map_err(EvalError::serialize)?;
+            prover.verify_shrink(&shrink_proof, &vk).map_err(EvalError::verify)?;
 
             #[cfg(not(feature = "cuda"))]
             let (wrap_proof, tmp_wrap_prove_duration) =
-                time_operation(|| prover.wrap_bn254(shrink_proof.clone(), opts).unwrap());
+                time_operation(|| prover.wrap_bn254(shrink_proof.clone(), opts));
 
             #[cfg(feature = "cuda")]
             let (wrap_proof, tmp_wrap_prove_duration) =
-                time_operation(|| server.wrap_bn254(shrink_proof.clone()).unwrap());
+                time_operation(|| server.wrap_bn254(shrink_proof.clone()));
+
+            let wrap_proof = wrap_proof.map_err(EvalError::prove)?;
 
             // TODO: FIX
             //
@@ -342,9 +489,9 @@ impl SP1Evaluator {
 
             prover
                 .verify_groth16_bn254(&groth16_proof, &vk, &pv, &artifacts_dir)
-                .expect("Proof verification failed");
-        } 
- 
+                .map_err(EvalError::verify)?;
+        }
+
         let plonk_prove_duration = time::Duration::from_secs(0);
         if args.plonk {
             todo!()
@@ -379,14 +526,43 @@ impl SP1Evaluator {
             plonk_prove_duration: plonk_prove_duration.as_secs_f64(),
             overall_khz,
             gas: gas_amount(&args.program),
-            hashes_per_second: hashes_per_second(&args.program, prove_duration),
-            hash_bytes_per_second: hash_bytes_per_second(&args.program, prove_duration),
+            hashes_per_second: hashes_per_second(args, hashed_bytes, prove_duration),
+            hash_bytes_per_second: hash_bytes_per_second(hashed_bytes, prove_duration),
+            // `compress` runs the whole recursion tree in one pipeline, so neither its layers nor
+            // its shape are observable.
+            compress_lift_count: None,
+            compress_join_count: None,
+            compress_tree_depth: None,
+            recursion_programs: None,
+            deferred_proofs: num_deferred_proofs,
+            inner_prove_duration: inner_prove_duration.map(|duration| duration.as_secs_f64()),
+            aggregation_prove_duration: inner_prove_duration
+                .map(|duration| (duration + prove_duration).as_secs_f64()),
+            tendermint_encoding: tendermint_encoding(args),
+            signature_count: signature_count(args),
+            signers: signers(args),
+            hash_message_size: hash_message_size(args),
+            hash_bytes: hash_bytes(args),
+            seed: args.seed,
+            precompiles: args.precompiles.to_string(),
+            elf_source: args.elf_source.to_string(),
+            elf_sha256: elf_sha256(&elf),
+            elf_size: elf.len() as u64,
+            elf_text_size: elf_text_size(&elf).unwrap_or_default(),
+            vkey: vk.bytes32(),
+            guest_opt_level: guest_profile(args).map(|profile| profile.opt_level.to_string()),
+            guest_lto: guest_profile(args)
+                .and_then(|profile| profile.lto.as_ref())
+                .map(|lto| lto.to_string()),
+            guest_codegen_units: guest_profile(args).map(|profile| profile.codegen_units),
+            guest_panic: guest_profile(args).map(|profile| profile.panic.to_string()),
+            ..Default::default()
         };
-        
+
         if std::env::var("SP1_PRINT").is_ok() {
             println!("{:#?}", report);
         }
-        
-        report
+
+        Ok(report)
     }
 }
@@ -0,0 +1,107 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    os::unix::process::ExitStatusExt,
+    panic,
+    path::Path,
+    process::{self, Command, ExitStatus},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{EvalArgs, RunStatus};
+
+/// The environment variable that marks the eval binary as the child that runs the workload.
+pub const CHILD_ENV: &str = "EVAL_CHILD";
+
+/// The environment variable with the file the child records the message of its first panic in.
+const PANIC_FILE_ENV: &str = "EVAL_PANIC_FILE";
+
+/// The exit code of a child whose evaluator returned an error, after writing the row of it.
+pub const FAILED_EXIT_CODE: i32 = 2;
+
+/// The signal the kernel's OOM killer sends.
+const SIGKILL: i32 = 9;
+
+/// How the child running the evaluation ended.
+pub enum Outcome {
+    /// The evaluation succeeded and the child wrote its row.
+    Ok,
+    /// The evaluator returned an error and the child wrote the row of it.
+    Failed,
+    /// The child was killed or died without writing a row, with the message it panicked with.
+    Killed(RunStatus, Option<String>),
+}
+
+/// How often the child is checked against the limits.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Runs the evaluation in a child process with the same arguments, killing it if it runs longer
+/// than `--timeout` or its resident memory grows past `--max-rss`, and returns how it ended.
+pub fn supervise(args: &EvalArgs) -> io::Result<Outcome> {
+    let panic_file = env::temp_dir().join(format!("eval-panic-{}", process::id()));
+    let _ = fs::remove_file(&panic_file);
+    let mut child = Command::new(env::current_exe()?)
+        .args(env::args_os().skip(1))
+        .env(CHILD_ENV, "1")
+        .env(PANIC_FILE_ENV, &panic_file)
+        .spawn()?;
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(outcome(status, panic_message(&panic_file)));
+        }
+        let timed_out = args.timeout.is_some_and(|timeout| start.elapsed().as_secs() >= timeout);
+        let out_of_memory = args
+            .max_rss
+            .is_some_and(|max_rss| rss_mib(child.id()).is_some_and(|rss| rss > max_rss));
+        if timed_out || out_of_memory {
+            child.kill()?;
+            child.wait()?;
+            let status = if timed_out { RunStatus::Timeout } else { RunStatus::Oom };
+            return Ok(Outcome::Killed(status, panic_message(&panic_file)));
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Returns how a child that exited on its own ended.
+fn outcome(status: ExitStatus, panic_message: Option<String>) -> Outcome {
+    match (status.code(), status.signal()) {
+        (Some(0), _) => Outcome::Ok,
+        (Some(FAILED_EXIT_CODE), _) => Outcome::Failed,
+        (_, Some(SIGKILL)) => Outcome::Killed(RunStatus::Oom, panic_message),
+        _ => Outcome::Killed(RunStatus::Panic, panic_message),
+    }
+}
+
+/// Makes the child record the message of its first panic for the supervisor, on top of printing
+/// it as usual.
+pub fn record_panics() {
+    let Some(panic_file) = env::var_os(PANIC_FILE_ENV) else {
+        return;
+    };
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        if let Ok(mut file) = OpenOptions::new().write(true).create_new(true).open(&panic_file) {
+            let _ = write!(file, "{}", info);
+        }
+    }));
+}
+
+/// Reads and removes the panic message the child recorded, if it panicked.
+fn panic_message(panic_file: &Path) -> Option<String> {
+    let message = fs::read_to_string(panic_file).ok();
+    let _ = fs::remove_file(panic_file);
+    message
+}
+
+/// Returns the resident memory of a process in MiB, read from `/proc`.
+fn rss_mib(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let kb = status.lines().find_map(|line| line.strip_prefix("VmRSS:"))?;
+    let kb = kb.trim().trim_end_matches("kB").trim().parse::<u64>().ok()?;
+    Some(kb / 1024)
+}
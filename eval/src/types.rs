@@ -14,6 +14,46 @@ pub enum ProgramId {
     Loop100m,
     Loop300m,
 
+    // Random access over a large heap array.
+    RandomAccess10k,
+    RandomAccess100k,
+    RandomAccess1m,
+    RandomAccess3m,
+    RandomAccess10m,
+    RandomAccess30m,
+    RandomAccess100m,
+    RandomAccess300m,
+
+    // Sequential memcpy.
+    Memcpy10k,
+    Memcpy100k,
+    Memcpy1m,
+    Memcpy3m,
+    Memcpy10m,
+    Memcpy30m,
+    Memcpy100m,
+    Memcpy300m,
+
+    // Sorting a large `Vec`.
+    Sort10k,
+    Sort100k,
+    Sort1m,
+    Sort3m,
+    Sort10m,
+    Sort30m,
+    Sort100m,
+    Sort300m,
+
+    // `BTreeMap` inserts.
+    Btreemap10k,
+    Btreemap100k,
+    Btreemap1m,
+    Btreemap3m,
+    Btreemap10m,
+    Btreemap30m,
+    Btreemap100m,
+    Btreemap300m,
+
     // Fibonacci.
     Fibonacci20k,
     Fibonacci200k,
@@ -41,12 +81,52 @@ pub enum ProgramId {
     Keccak2563mb,
     Keccak25610mb,
 
+    // BLAKE2s.
+    Blake2s100kb,
+    Blake2s1mb,
+    Blake2s10mb,
+
+    // BLAKE3.
+    Blake3100kb,
+    Blake31mb,
+    Blake310mb,
+
+    // RIPEMD-160.
+    Ripemd160100kb,
+    Ripemd1601mb,
+    Ripemd16010mb,
+
+    // Poseidon2 over BabyBear.
+    Poseidon2Babybear10kb,
+    Poseidon2Babybear100kb,
+    Poseidon2Babybear1mb,
+
+    // Poseidon2 over BN254.
+    Poseidon2Bn25410kb,
+    Poseidon2Bn254100kb,
+    Poseidon2Bn2541mb,
+
+    // Big integers and pairings, accelerated and as a pure-Rust baseline.
+    Mulmod256,
+    Mulmod256Baseline,
+    Modexp2048,
+    Modexp2048Baseline,
+    Modexp4096,
+    Modexp4096Baseline,
+    Bn254Pairing,
+    Bn254PairingBaseline,
+    Bls12381Pairing,
+    Bls12381PairingBaseline,
+
     // SSZ Withdrawals.
-    SSZWithdrawals,
+    SSZWithdrawals1x,
+    SSZWithdrawals10x,
+    SSZWithdrawals100x,
 
     // Tendermint.
     Tendermint,
-    
+    TendermintSkipping,
+
     // RSP
     Rsp20526626,
     Rsp20526627,
@@ -58,22 +138,33 @@ pub enum ProgramId {
     Rsp20528710,
     Rsp20528711,
     Rsp20528712,
-    
+
     // Signatures
-    ECDSAVerify,    
+    ECDSAVerify,
     EDDSAVerify,
+    P256Verify,
+    SchnorrVerify,
+    BLSAggregateVerify,
 
-    Helios,
+    // Helios, by the number of sync committee updates applied before the finality update.
+    Helios0,
+    Helios1,
+    Helios4,
 
     Groth16ProofVerify,
 
     ZKEmail,
+
+    // Aggregation.
+    Aggregation1,
+    Aggregation4,
+    Aggregation16,
 }
 
 impl ProgramId {
     /// The "priority" of a program is used to sort the programs in the performance report.
     ///
-    /// The higher the priority, the more work the proof requires. 
+    /// The higher the priority, the more work the proof requires.
     pub(crate) fn priority(&self) -> usize {
         match self {
             // Loop
@@ -85,7 +176,47 @@ impl ProgramId {
             ProgramId::Loop30m => 6,
             ProgramId::Loop100m => 7,
             ProgramId::Loop300m => 8,
-            
+
+            // Random access over a large heap array
+            ProgramId::RandomAccess10k => 1,
+            ProgramId::RandomAccess100k => 2,
+            ProgramId::RandomAccess1m => 3,
+            ProgramId::RandomAccess3m => 4,
+            ProgramId::RandomAccess10m => 5,
+            ProgramId::RandomAccess30m => 6,
+            ProgramId::RandomAccess100m => 7,
+            ProgramId::RandomAccess300m => 8,
+
+            // Sequential memcpy
+            ProgramId::Memcpy10k => 1,
+            ProgramId::Memcpy100k => 2,
+            ProgramId::Memcpy1m => 3,
+            ProgramId::Memcpy3m => 4,
+            ProgramId::Memcpy10m => 5,
+            ProgramId::Memcpy30m => 6,
+            ProgramId::Memcpy100m => 7,
+            ProgramId::Memcpy300m => 8,
+
+            // Sorting a large `Vec`
+            ProgramId::Sort10k => 1,
+            ProgramId::Sort100k => 2,
+            ProgramId::Sort1m => 3,
+            ProgramId::Sort3m => 4,
+            ProgramId::Sort10m => 5,
+            ProgramId::Sort30m => 6,
+            ProgramId::Sort100m => 7,
+            ProgramId::Sort300m => 8,
+
+            // `BTreeMap` inserts
+            ProgramId::Btreemap10k => 1,
+            ProgramId::Btreemap100k => 2,
+            ProgramId::Btreemap1m => 3,
+            ProgramId::Btreemap3m => 4,
+            ProgramId::Btreemap10m => 5,
+            ProgramId::Btreemap30m => 6,
+            ProgramId::Btreemap100m => 7,
+            ProgramId::Btreemap300m => 8,
+
             // Fibonacci
             ProgramId::Fibonacci20k => 1,
             ProgramId::Fibonacci200k => 2,
@@ -98,27 +229,67 @@ impl ProgramId {
             ProgramId::Fibonacci1b => 9,
             ProgramId::Fibonacci2b => 10,
             ProgramId::Fibonacci4b => 11,
-            
+
             // SHA-256
             ProgramId::Sha256100kb => 1,
             ProgramId::Sha256300kb => 2,
             ProgramId::Sha2561mb => 3,
             ProgramId::Sha2563mb => 4,
             ProgramId::Sha25610mb => 5,
-            
+
             // Keccak-256
             ProgramId::Keccak256100kb => 1,
             ProgramId::Keccak256300kb => 2,
             ProgramId::Keccak2561mb => 3,
             ProgramId::Keccak2563mb => 4,
             ProgramId::Keccak25610mb => 5,
-            
+
+            // BLAKE2s
+            ProgramId::Blake2s100kb => 1,
+            ProgramId::Blake2s1mb => 2,
+            ProgramId::Blake2s10mb => 3,
+
+            // BLAKE3
+            ProgramId::Blake3100kb => 1,
+            ProgramId::Blake31mb => 2,
+            ProgramId::Blake310mb => 3,
+
+            // RIPEMD-160
+            ProgramId::Ripemd160100kb => 1,
+            ProgramId::Ripemd1601mb => 2,
+            ProgramId::Ripemd16010mb => 3,
+
+            // Poseidon2 over BabyBear
+            ProgramId::Poseidon2Babybear10kb => 1,
+            ProgramId::Poseidon2Babybear100kb => 2,
+            ProgramId::Poseidon2Babybear1mb => 3,
+
+            // Poseidon2 over BN254
+            ProgramId::Poseidon2Bn25410kb => 1,
+            ProgramId::Poseidon2Bn254100kb => 2,
+            ProgramId::Poseidon2Bn2541mb => 3,
+
+            // Big integers and pairings
+            ProgramId::Mulmod256 => 1,
+            ProgramId::Mulmod256Baseline => 1,
+            ProgramId::Modexp2048 => 1,
+            ProgramId::Modexp2048Baseline => 1,
+            ProgramId::Modexp4096 => 2,
+            ProgramId::Modexp4096Baseline => 2,
+            ProgramId::Bn254Pairing => 1,
+            ProgramId::Bn254PairingBaseline => 1,
+            ProgramId::Bls12381Pairing => 1,
+            ProgramId::Bls12381PairingBaseline => 1,
+
             // SSZ Withdrawals
-            ProgramId::SSZWithdrawals => 1,
-            
+            ProgramId::SSZWithdrawals1x => 1,
+            ProgramId::SSZWithdrawals10x => 2,
+            ProgramId::SSZWithdrawals100x => 3,
+
             // Tendermint
             ProgramId::Tendermint => 1,
-            
+            ProgramId::TendermintSkipping => 2,
+
             // RSP
             ProgramId::Rsp20526626 => 1,
             ProgramId::Rsp20526627 => 1,
@@ -130,18 +301,194 @@ impl ProgramId {
             ProgramId::Rsp20528710 => 1,
             ProgramId::Rsp20528711 => 1,
             ProgramId::Rsp20528712 => 1,
-            
+
             // Signatures
             ProgramId::ECDSAVerify => 1,
             ProgramId::EDDSAVerify => 1,
+            ProgramId::P256Verify => 1,
+            ProgramId::SchnorrVerify => 1,
+            ProgramId::BLSAggregateVerify => 1,
 
-            ProgramId::Helios => 1,
+            ProgramId::Helios0 => 1,
+            ProgramId::Helios1 => 2,
+            ProgramId::Helios4 => 3,
 
             ProgramId::Groth16ProofVerify => 1,
 
             ProgramId::ZKEmail => 1,
+
+            // Aggregation
+            ProgramId::Aggregation1 => 1,
+            ProgramId::Aggregation4 => 2,
+            ProgramId::Aggregation16 => 3,
         }
     }
+
+    /// The workload in `programs` whose guest runs the program, and whether the workload has a
+    /// manifest per prover in `programs/<workload>/<prover>` instead of a single crate.
+    fn workload(&self) -> (&'static str, bool) {
+        match self {
+            ProgramId::Loop10k
+            | ProgramId::Loop100k
+            | ProgramId::Loop1m
+            | ProgramId::Loop3m
+            | ProgramId::Loop10m
+            | ProgramId::Loop30m
+            | ProgramId::Loop100m
+            | ProgramId::Loop300m => ("loop", false),
+
+            ProgramId::RandomAccess10k
+            | ProgramId::RandomAccess100k
+            | ProgramId::RandomAccess1m
+            | ProgramId::RandomAccess3m
+            | ProgramId::RandomAccess10m
+            | ProgramId::RandomAccess30m
+            | ProgramId::RandomAccess100m
+            | ProgramId::RandomAccess300m
+            | ProgramId::Memcpy10k
+            | ProgramId::Memcpy100k
+            | ProgramId::Memcpy1m
+            | ProgramId::Memcpy3m
+            | ProgramId::Memcpy10m
+            | ProgramId::Memcpy30m
+            | ProgramId::Memcpy100m
+            | ProgramId::Memcpy300m
+            | ProgramId::Sort10k
+            | ProgramId::Sort100k
+            | ProgramId::Sort1m
+            | ProgramId::Sort3m
+            | ProgramId::Sort10m
+            | ProgramId::Sort30m
+            | ProgramId::Sort100m
+            | ProgramId::Sort300m
+            | ProgramId::Btreemap10k
+            | ProgramId::Btreemap100k
+            | ProgramId::Btreemap1m
+            | ProgramId::Btreemap3m
+            | ProgramId::Btreemap10m
+            | ProgramId::Btreemap30m
+            | ProgramId::Btreemap100m
+            | ProgramId::Btreemap300m => ("memory", false),
+
+            ProgramId::Fibonacci20k
+            | ProgramId::Fibonacci200k
+            | ProgramId::Fibonacci2m
+            | ProgramId::Fibonacci4m
+            | ProgramId::Fibonacci20m
+            | ProgramId::Fibonacci40m
+            | ProgramId::Fibonacci200m
+            | ProgramId::Fibonacci400m
+            | ProgramId::Fibonacci1b
+            | ProgramId::Fibonacci2b
+            | ProgramId::Fibonacci4b => ("fibonacci", false),
+
+            ProgramId::Sha256100kb
+            | ProgramId::Sha256300kb
+            | ProgramId::Sha2561mb
+            | ProgramId::Sha2563mb
+            | ProgramId::Sha25610mb => ("sha256", true),
+
+            ProgramId::Keccak256100kb
+            | ProgramId::Keccak256300kb
+            | ProgramId::Keccak2561mb
+            | ProgramId::Keccak2563mb
+            | ProgramId::Keccak25610mb => ("keccak256", true),
+
+            ProgramId::Blake2s100kb | ProgramId::Blake2s1mb | ProgramId::Blake2s10mb => {
+                ("blake2s", false)
+            }
+            ProgramId::Blake3100kb | ProgramId::Blake31mb | ProgramId::Blake310mb => {
+                ("blake3", false)
+            }
+            ProgramId::Ripemd160100kb | ProgramId::Ripemd1601mb | ProgramId::Ripemd16010mb => {
+                ("ripemd160", false)
+            }
+            ProgramId::Poseidon2Babybear10kb
+            | ProgramId::Poseidon2Babybear100kb
+            | ProgramId::Poseidon2Babybear1mb => ("poseidon2-babybear", false),
+            ProgramId::Poseidon2Bn25410kb
+            | ProgramId::Poseidon2Bn254100kb
+            | ProgramId::Poseidon2Bn2541mb => ("poseidon2-bn254", false),
+
+            ProgramId::Mulmod256 | ProgramId::Mulmod256Baseline => ("mulmod256", false),
+            ProgramId::Modexp2048
+            | ProgramId::Modexp2048Baseline
+            | ProgramId::Modexp4096
+            | ProgramId::Modexp4096Baseline => ("modexp", false),
+            ProgramId::Bn254Pairing | ProgramId::Bn254PairingBaseline => ("bn254-pairing", false),
+            ProgramId::Bls12381Pairing | ProgramId::Bls12381PairingBaseline => {
+                ("bls12-381-pairing", false)
+            }
+
+            ProgramId::SSZWithdrawals1x
+            | ProgramId::SSZWithdrawals10x
+            | ProgramId::SSZWithdrawals100x => ("ssz-withdrawals", false),
+
+            ProgramId::Tendermint | ProgramId::TendermintSkipping => ("tendermint", true),
+
+            ProgramId::Rsp20526626
+            | ProgramId::Rsp20526627
+            | ProgramId::Rsp20526628
+            | ProgramId::Rsp20526629
+            | ProgramId::Rsp20526630
+            | ProgramId::Rsp20528708
+            | ProgramId::Rsp20528709
+            | ProgramId::Rsp20528710
+            | ProgramId::Rsp20528711
+            | ProgramId::Rsp20528712 => ("rsp", true),
+
+            ProgramId::ECDSAVerify => ("ecdsa-verify", true),
+            ProgramId::EDDSAVerify => ("eddsa-verify", true),
+            ProgramId::P256Verify => ("p256-verify", true),
+            ProgramId::SchnorrVerify => ("schnorr-verify", true),
+            ProgramId::BLSAggregateVerify => ("bls-aggregate-verify", true),
+
+            ProgramId::Helios0 | ProgramId::Helios1 | ProgramId::Helios4 => ("helios", true),
+
+            ProgramId::Groth16ProofVerify => ("groth", true),
+
+            ProgramId::ZKEmail => ("zk-email", true),
+
+            ProgramId::Aggregation1 | ProgramId::Aggregation4 | ProgramId::Aggregation16 => {
+                ("aggregation", false)
+            }
+        }
+    }
+
+    /// Whether the program is the pure-Rust baseline of another, which always proves the unpatched
+    /// build of the same guest.
+    pub(crate) fn is_baseline(&self) -> bool {
+        matches!(
+            self,
+            ProgramId::Mulmod256Baseline
+                | ProgramId::Modexp2048Baseline
+                | ProgramId::Modexp4096Baseline
+                | ProgramId::Bn254PairingBaseline
+                | ProgramId::Bls12381PairingBaseline
+        )
+    }
+
+    /// The guest crate that runs the program on the given prover.
+    pub(crate) fn guest(&self, prover: &ProverId) -> Guest {
+        let (workload, per_prover) = self.workload();
+        if per_prover {
+            Guest {
+                dir: format!("{}/{}", workload, prover),
+                elf_name: format!("{}-{}", workload, prover),
+            }
+        } else {
+            Guest { dir: workload.to_string(), elf_name: workload.to_string() }
+        }
+    }
+}
+
+/// The guest crate that runs a program on a prover.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Guest {
+    /// The directory of the crate in `programs`.
+    pub dir: String,
+    /// The name of the binary the crate builds, which is also the name of its ELF.
+    pub elf_name: String,
 }
 
 /// An identifier used to select the prover to evaluate.
@@ -158,6 +505,105 @@ pub enum HashFnId {
     Poseidon,
     Blake3,
     Keccak256,
+    Blake2b,
+}
+
+/// An identifier used to select the kind of receipt RISC Zero compresses the core proof into.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum Risc0ReceiptKind {
+    Composite,
+    Succinct,
+    Groth16,
+}
+
+/// An identifier used to select how light blocks are encoded for the tendermint guests.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum TendermintEncoding {
+    Protobuf,
+    Json,
+}
+
+/// An identifier used to select whether the signatures of a signature program come from distinct
+/// signers or from one repeated signer.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum Signers {
+    Distinct,
+    Repeated,
+}
+
+/// An identifier used to select whether the guest is proven as built with its accelerated crates,
+/// as built without them, or both.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum Precompiles {
+    On,
+    Off,
+    Ab,
+}
+
+/// An identifier used to select where the ELF that is proven comes from.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum ElfSource {
+    /// The ELF that `eval build` builds from the guest's sources.
+    Built,
+    /// The ELF checked in to the guest's `elf` directory.
+    CheckedIn,
+    /// The ELF at `--elf-path`.
+    Path,
+}
+
+/// How the evaluation of a program ended.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RunStatus {
+    Ok,
+    /// The evaluation ran longer than `--timeout`.
+    Timeout,
+    /// The evaluation grew past `--max-rss` or was killed by the kernel's OOM killer.
+    Oom,
+    /// The evaluation panicked or died otherwise.
+    Panic,
+    /// The ELF to prove does not exist or has no code.
+    MissingElf,
+    /// The guest panicked, or halted with a non-zero exit code.
+    GuestPanic,
+    /// The prover failed to set up, prove or compress.
+    ProveFailed,
+    /// A proof failed to verify.
+    VerifyFailed,
+    /// A proof failed to serialize.
+    SerializeFailed,
+}
+
+/// An identifier used to select the optimization level the guest is built with.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum GuestOptLevel {
+    #[value(name = "2")]
+    O2,
+    #[value(name = "3")]
+    O3,
+    S,
+    Z,
+}
+
+/// An identifier used to select the link-time optimization the guest is built with.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum GuestLto {
+    Off,
+    Thin,
+    Fat,
+}
+
+/// An identifier used to select the panic strategy the guest is built with.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum GuestPanic {
+    Abort,
+    Unwind,
+}
+
+/// An identifier used to select the bytes the hashing programs hash.
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum HashBytes {
+    Zero,
+    Random,
 }
 
 impl Display for ProgramId {
@@ -171,6 +617,38 @@ impl Display for ProgramId {
             ProgramId::Loop30m => write!(f, "loop-30m"),
             ProgramId::Loop100m => write!(f, "loop-100m"),
             ProgramId::Loop300m => write!(f, "loop-300m"),
+            ProgramId::RandomAccess10k => write!(f, "random-access-10k"),
+            ProgramId::RandomAccess100k => write!(f, "random-access-100k"),
+            ProgramId::RandomAccess1m => write!(f, "random-access-1m"),
+            ProgramId::RandomAccess3m => write!(f, "random-access-3m"),
+            ProgramId::RandomAccess10m => write!(f, "random-access-10m"),
+            ProgramId::RandomAccess30m => write!(f, "random-access-30m"),
+            ProgramId::RandomAccess100m => write!(f, "random-access-100m"),
+            ProgramId::RandomAccess300m => write!(f, "random-access-300m"),
+            ProgramId::Memcpy10k => write!(f, "memcpy-10k"),
+            ProgramId::Memcpy100k => write!(f, "memcpy-100k"),
+            ProgramId::Memcpy1m => write!(f, "memcpy-1m"),
+            ProgramId::Memcpy3m => write!(f, "memcpy-3m"),
+            ProgramId::Memcpy10m => write!(f, "memcpy-10m"),
+            ProgramId::Memcpy30m => write!(f, "memcpy-30m"),
+            ProgramId::Memcpy100m => write!(f, "memcpy-100m"),
+            ProgramId::Memcpy300m => write!(f, "memcpy-300m"),
+            ProgramId::Sort10k => write!(f, "sort-10k"),
+            ProgramId::Sort100k => write!(f, "sort-100k"),
+            ProgramId::Sort1m => write!(f, "sort-1m"),
+            ProgramId::Sort3m => write!(f, "sort-3m"),
+            ProgramId::Sort10m => write!(f, "sort-10m"),
+            ProgramId::Sort30m => write!(f, "sort-30m"),
+            ProgramId::Sort100m => write!(f, "sort-100m"),
+            ProgramId::Sort300m => write!(f, "sort-300m"),
+            ProgramId::Btreemap10k => write!(f, "btreemap-10k"),
+            ProgramId::Btreemap100k => write!(f, "btreemap-100k"),
+            ProgramId::Btreemap1m => write!(f, "btreemap-1m"),
+            ProgramId::Btreemap3m => write!(f, "btreemap-3m"),
+            ProgramId::Btreemap10m => write!(f, "btreemap-10m"),
+            ProgramId::Btreemap30m => write!(f, "btreemap-30m"),
+            ProgramId::Btreemap100m => write!(f, "btreemap-100m"),
+            ProgramId::Btreemap300m => write!(f, "btreemap-300m"),
             ProgramId::Fibonacci20k => write!(f, "fibonacci-20k"),
             ProgramId::Fibonacci200k => write!(f, "fibonacci-200k"),
             ProgramId::Fibonacci2m => write!(f, "fibonacci-2m"),
@@ -192,8 +670,36 @@ impl Display for ProgramId {
             ProgramId::Keccak2561mb => write!(f, "keccak256-1mb"),
             ProgramId::Keccak2563mb => write!(f, "keccak256-3mb"),
             ProgramId::Keccak25610mb => write!(f, "keccak256-10mb"),
-            ProgramId::SSZWithdrawals => write!(f, "ssz-withdrawals"),
+            ProgramId::Blake2s100kb => write!(f, "blake2s-100kb"),
+            ProgramId::Blake2s1mb => write!(f, "blake2s-1mb"),
+            ProgramId::Blake2s10mb => write!(f, "blake2s-10mb"),
+            ProgramId::Blake3100kb => write!(f, "blake3-100kb"),
+            ProgramId::Blake31mb => write!(f, "blake3-1mb"),
+            ProgramId::Blake310mb => write!(f, "blake3-10mb"),
+            ProgramId::Ripemd160100kb => write!(f, "ripemd160-100kb"),
+            ProgramId::Ripemd1601mb => write!(f, "ripemd160-1mb"),
+            ProgramId::Ripemd16010mb => write!(f, "ripemd160-10mb"),
+            ProgramId::Poseidon2Babybear10kb => write!(f, "poseidon2-babybear-10kb"),
+            ProgramId::Poseidon2Babybear100kb => write!(f, "poseidon2-babybear-100kb"),
+            ProgramId::Poseidon2Babybear1mb => write!(f, "poseidon2-babybear-1mb"),
+            ProgramId::Poseidon2Bn25410kb => write!(f, "poseidon2-bn254-10kb"),
+            ProgramId::Poseidon2Bn254100kb => write!(f, "poseidon2-bn254-100kb"),
+            ProgramId::Poseidon2Bn2541mb => write!(f, "poseidon2-bn254-1mb"),
+            ProgramId::Mulmod256 => write!(f, "mulmod256"),
+            ProgramId::Mulmod256Baseline => write!(f, "mulmod256-baseline"),
+            ProgramId::Modexp2048 => write!(f, "modexp-2048"),
+            ProgramId::Modexp2048Baseline => write!(f, "modexp-2048-baseline"),
+            ProgramId::Modexp4096 => write!(f, "modexp-4096"),
+            ProgramId::Modexp4096Baseline => write!(f, "modexp-4096-baseline"),
+            ProgramId::Bn254Pairing => write!(f, "bn254-pairing"),
+            ProgramId::Bn254PairingBaseline => write!(f, "bn254-pairing-baseline"),
+            ProgramId::Bls12381Pairing => write!(f, "bls12-381-pairing"),
+            ProgramId::Bls12381PairingBaseline => write!(f, "bls12-381-pairing-baseline"),
+            ProgramId::SSZWithdrawals1x => write!(f, "ssz-withdrawals-1x"),
+            ProgramId::SSZWithdrawals10x => write!(f, "ssz-withdrawals-10x"),
+            ProgramId::SSZWithdrawals100x => write!(f, "ssz-withdrawals-100x"),
             ProgramId::Tendermint => write!(f, "tendermint"),
+            ProgramId::TendermintSkipping => write!(f, "tendermint-skipping"),
             ProgramId::Rsp20526626 => write!(f, "rsp-20526626"),
             ProgramId::Rsp20526627 => write!(f, "rsp-20526627"),
             ProgramId::Rsp20526628 => write!(f, "rsp-20526628"),
@@ -206,9 +712,17 @@ impl Display for ProgramId {
             ProgramId::Rsp20528712 => write!(f, "rsp-20528712"),
             ProgramId::ECDSAVerify => write!(f, "ecdsa-verify"),
             ProgramId::EDDSAVerify => write!(f, "eddsa-verify"),
-            ProgramId::Helios => write!(f, "helios"),
+            ProgramId::P256Verify => write!(f, "p256-verify"),
+            ProgramId::SchnorrVerify => write!(f, "schnorr-verify"),
+            ProgramId::BLSAggregateVerify => write!(f, "bls-aggregate-verify"),
+            ProgramId::Helios0 => write!(f, "helios-0"),
+            ProgramId::Helios1 => write!(f, "helios-1"),
+            ProgramId::Helios4 => write!(f, "helios-4"),
             ProgramId::Groth16ProofVerify => write!(f, "groth16-proof-verify"),
             ProgramId::ZKEmail => write!(f, "zk-email"),
+            ProgramId::Aggregation1 => write!(f, "aggregation1"),
+            ProgramId::Aggregation4 => write!(f, "aggregation4"),
+            ProgramId::Aggregation16 => write!(f, "aggregation16"),
         }
     }
 }
@@ -229,6 +743,110 @@ impl Display for HashFnId {
             HashFnId::Poseidon => write!(f, "poseidon"),
             HashFnId::Blake3 => write!(f, "blake3"),
             HashFnId::Keccak256 => write!(f, "keccak256"),
+            HashFnId::Blake2b => write!(f, "blake2b"),
+        }
+    }
+}
+
+impl Display for Risc0ReceiptKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Risc0ReceiptKind::Composite => write!(f, "composite"),
+            Risc0ReceiptKind::Succinct => write!(f, "succinct"),
+            Risc0ReceiptKind::Groth16 => write!(f, "groth16"),
+        }
+    }
+}
+
+impl Display for TendermintEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TendermintEncoding::Protobuf => write!(f, "protobuf"),
+            TendermintEncoding::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl Display for Signers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Signers::Distinct => write!(f, "distinct"),
+            Signers::Repeated => write!(f, "repeated"),
+        }
+    }
+}
+
+impl Display for HashBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashBytes::Zero => write!(f, "zero"),
+            HashBytes::Random => write!(f, "random"),
+        }
+    }
+}
+
+impl Display for Precompiles {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Precompiles::On => write!(f, "on"),
+            Precompiles::Off => write!(f, "off"),
+            Precompiles::Ab => write!(f, "ab"),
+        }
+    }
+}
+
+impl Display for ElfSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElfSource::Built => write!(f, "built"),
+            ElfSource::CheckedIn => write!(f, "checked-in"),
+            ElfSource::Path => write!(f, "path"),
+        }
+    }
+}
+
+impl Display for GuestOptLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuestOptLevel::O2 => write!(f, "2"),
+            GuestOptLevel::O3 => write!(f, "3"),
+            GuestOptLevel::S => write!(f, "s"),
+            GuestOptLevel::Z => write!(f, "z"),
+        }
+    }
+}
+
+impl Display for GuestLto {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuestLto::Off => write!(f, "off"),
+            GuestLto::Thin => write!(f, "thin"),
+            GuestLto::Fat => write!(f, "fat"),
+        }
+    }
+}
+
+impl Display for GuestPanic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuestPanic::Abort => write!(f, "abort"),
+            GuestPanic::Unwind => write!(f, "unwind"),
+        }
+    }
+}
+
+impl Display for RunStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunStatus::Ok => write!(f, "ok"),
+            RunStatus::Timeout => write!(f, "timeout"),
+            RunStatus::Oom => write!(f, "oom"),
+            RunStatus::Panic => write!(f, "panic"),
+            RunStatus::MissingElf => write!(f, "missing_elf"),
+            RunStatus::GuestPanic => write!(f, "guest_panic"),
+            RunStatus::ProveFailed => write!(f, "prove_failed"),
+            RunStatus::VerifyFailed => write!(f, "verify_failed"),
+            RunStatus::SerializeFailed => write!(f, "serialize_failed"),
         }
     }
 }
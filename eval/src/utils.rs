@@ -1,81 +1,131 @@
 use core::time;
 use std::{
     env, fs,
+    path::Path,
     time::{Duration, Instant},
 };
 
-use k256::ecdsa::signature::SignerMut;
+use clap::ValueEnum;
+use num_bigint::BigUint;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use sp1_reth_primitives::SP1RethInput;
 
-use crate::{EvalArgs, ProgramId, ProverId};
+use crate::{
+    build::GuestProfile, ElfSource, EvalArgs, EvalError, Guest, HashBytes, Precompiles, ProgramId,
+    ProverId, Signers, TendermintEncoding,
+};
 
-pub fn get_elf(args: &EvalArgs) -> String {
-    let mut program_dir = args.program.to_string();
-    if args.program == ProgramId::Tendermint {
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
-    }
-    if program_dir.starts_with("loop") {
-        program_dir = "loop".to_string();
-    }
-    if program_dir.starts_with("fibonacci") {
-        program_dir = "fibonacci".to_string();
-    }
-    if program_dir.starts_with("sha256") {
-        program_dir = "sha256".to_string();
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
-    }
-    if program_dir.starts_with("keccak256") {
-        program_dir = "keccak256".to_string();
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
-    }
-    if program_dir.starts_with("rsp") {
-        program_dir = "rsp".to_string();
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
-    }
-    if program_dir.starts_with("ecdsa-verify") {
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
-    }
-    if program_dir.starts_with("eddsa-verify") {
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
-    }
-    if program_dir.starts_with("helios") {
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
+/// Reads the ELF that `--elf-source` selects for the guest of the program.
+pub fn get_elf(args: &EvalArgs) -> Result<Vec<u8>, EvalError> {
+    let guest = args.program.guest(&args.prover);
+    let unpatched = args.precompiles == Precompiles::Off;
+    let elf_path_str = match args.elf_source {
+        ElfSource::Built => program_elf_path(&guest, &args.prover, unpatched),
+        ElfSource::CheckedIn => checked_in_elf_path(&guest),
+        ElfSource::Path => args.elf_path.clone().ok_or_else(|| {
+            EvalError::MissingElf("`--elf-source path` needs `--elf-path`".to_string())
+        })?,
+    };
+    println!("elf path: {}", elf_path_str);
+    if !Path::new(&elf_path_str).exists() {
+        return Err(EvalError::MissingElf(match args.elf_source {
+            ElfSource::Built => format!(
+                "the guest of {} is not built at {}, build it with `eval build --program {} --prover {}`",
+                args.program,
+                elf_path_str,
+                args.program.to_possible_value().unwrap().get_name(),
+                args.prover.to_possible_value().unwrap().get_name(),
+            ),
+            ElfSource::CheckedIn => {
+                format!("the guest of {} has no checked-in ELF at {}", args.program, elf_path_str)
+            }
+            ElfSource::Path => format!("there is no ELF at {}", elf_path_str),
+        }));
     }
-    if program_dir.starts_with("groth16-proof-verify") {
-        program_dir = "groth".to_string();
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
+    let elf = fs::read(&elf_path_str).map_err(|err| {
+        EvalError::MissingElf(format!("reading {} failed: {}", elf_path_str, err))
+    })?;
+    if !elf.starts_with(b"\x7fELF") {
+        return Err(EvalError::MissingElf(format!("{} is not an ELF", elf_path_str)));
     }
-    if program_dir.starts_with("zk-email") {
-        program_dir = "zk-email".to_string();
-        program_dir += "-";
-        program_dir += args.prover.to_string().as_str();
+    match elf_text_size(&elf) {
+        None => Err(EvalError::MissingElf(format!(
+            "{} is not a 32-bit ELF or is truncated",
+            elf_path_str
+        ))),
+        // Some of the checked-in ELFs are placeholders without any code.
+        Some(0) => {
+            Err(EvalError::MissingElf(format!("{} has no executable code to prove", elf_path_str)))
+        }
+        Some(_) => Ok(elf),
     }
+}
 
+/// Returns the path to the ELF checked in to the `elf` directory of the given guest. The checked-in
+/// ELFs are named after the SP1 target, whichever prover they are built for.
+pub fn checked_in_elf_path(guest: &Guest) -> String {
     let current_dir = env::current_dir().expect("Failed to get current working directory");
+    let elf_path =
+        current_dir.join(format!("programs/{}/elf/riscv32im-succinct-zkvm-elf", guest.dir));
+    elf_path.to_str().expect("Failed to convert path to string").to_string()
+}
+
+/// Returns the path to the ELF built from the given guest for the given prover, or to its
+/// unpatched build, which `eval build` places in `target/unpatched`.
+pub fn program_elf_path(guest: &Guest, prover: &ProverId, unpatched: bool) -> String {
+    let current_dir = env::current_dir().expect("Failed to get current working directory");
+    let target_dir = if unpatched { "target/unpatched" } else { "target" };
 
     let mut elf_path = current_dir.join(format!(
-        "programs/{}/target/riscv32im-succinct-zkvm-elf/release/{}",
-        program_dir, program_dir
+        "programs/{}/{}/riscv32im-succinct-zkvm-elf/release/{}",
+        guest.dir, target_dir, guest.elf_name
     ));
 
-    if args.prover == ProverId::Risc0 {
+    if *prover == ProverId::Risc0 {
         elf_path = current_dir.join(format!(
-            "programs/{}/target/riscv32im-risc0-zkvm-elf/release/{}",
-            program_dir, program_dir
+            "programs/{}/{}/riscv32im-risc0-zkvm-elf/release/{}",
+            guest.dir, target_dir, guest.elf_name
         ));
     }
 
-    let elf_path_str = elf_path.to_str().expect("Failed to convert path to string").to_string();
-    println!("elf path: {}", elf_path_str);
-    elf_path_str
+    elf_path.to_str().expect("Failed to convert path to string").to_string()
+}
+
+/// Returns the SHA-256 of an ELF, as hex.
+pub fn elf_sha256(elf: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(elf))
+}
+
+/// Returns the size in bytes of the executable segments of a 32-bit little-endian ELF, which the
+/// zkVM guests are, or `None` if it is not a 32-bit ELF or its headers are cut off.
+pub fn elf_text_size(elf: &[u8]) -> Option<u64> {
+    const ELFCLASS32: u8 = 1;
+    const PT_LOAD: u32 = 1;
+    const PF_X: u32 = 1;
+
+    let u16_at =
+        |offset: usize| Some(u16::from_le_bytes(elf.get(offset..offset + 2)?.try_into().ok()?));
+    let u32_at =
+        |offset: usize| Some(u32::from_le_bytes(elf.get(offset..offset + 4)?.try_into().ok()?));
+    if !elf.starts_with(b"\x7fELF") || elf.get(4) != Some(&ELFCLASS32) {
+        return None;
+    }
+
+    let program_headers = u32_at(28)? as usize;
+    let entry_size = u16_at(42)? as usize;
+    let entries = u16_at(44)? as usize;
+    (0..entries).try_fold(0, |size, i| {
+        let header = program_headers + i * entry_size;
+        let executable = u32_at(header)? == PT_LOAD && u32_at(header + 24)? & PF_X != 0;
+        Some(if executable { size + u32_at(header + 16)? as u64 } else { size })
+    })
+}
+
+/// Returns the compiler settings the proven ELF was built with, if `eval build` built it.
+pub fn guest_profile(args: &EvalArgs) -> Option<&GuestProfile> {
+    (args.elf_source == ElfSource::Built).then_some(&args.guest_profile)
 }
 
 pub fn time_operation<T, F: FnOnce() -> T>(operation: F) -> (T, time::Duration) {
@@ -103,35 +153,211 @@ pub fn gas_amount(program: &ProgramId) -> Option<u64> {
     Some(amount)
 }
 
-/// The hashes per second are given by the block size of the hash function
-pub fn hashes_per_second(program: &ProgramId, core_compress_duration: Duration) -> Option<f64> {
-    let word_size_bytes: u64 = match program {
+/// The number of inner `fibonacci-20k` proofs verified by an aggregation program.
+pub fn aggregation_size(program: &ProgramId) -> Option<usize> {
+    let size = match program {
+        ProgramId::Aggregation1 => 1,
+        ProgramId::Aggregation4 => 4,
+        ProgramId::Aggregation16 => 16,
+        _ => return None,
+    };
+
+    Some(size)
+}
+
+/// The digest of the inner outputs that the aggregation programs commit: the SHA-256 of the
+/// SHA-256 of each output, in order.
+pub fn aggregation_outputs_digest(outputs: &[Vec<u8>]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for output in outputs {
+        hasher.update(Sha256::digest(output));
+    }
+    hasher.finalize().into()
+}
+
+/// The guest of the inner `fibonacci-20k` proofs verified by the aggregation programs.
+pub fn aggregation_inner_guest(prover: &ProverId) -> Guest {
+    ProgramId::Fibonacci20k.guest(prover)
+}
+
+/// Reads the ELF of the inner `fibonacci-20k` proofs, which is always built accelerated.
+pub fn aggregation_inner_elf(prover: &ProverId) -> Result<Vec<u8>, EvalError> {
+    let elf_path = program_elf_path(&aggregation_inner_guest(prover), prover, false);
+    fs::read(&elf_path).map_err(|err| {
+        EvalError::MissingElf(format!("reading the inner ELF {} failed: {}", elf_path, err))
+    })
+}
+
+/// Parameters of an `ssz-withdrawals` run, read by the guest before the witness.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SszWithdrawalsParams {
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub eigenpod_address: [u8; 20],
+    /// How many times the withdrawal proofs of the slot range are verified.
+    pub repetitions: u32,
+}
+
+/// The `ssz-withdrawals` parameters of a program size. All sizes prove the slot range covered by
+/// `fixtures/ssz-withdrawals/witness.bin` and differ only in the repetition count.
+pub fn ssz_withdrawals_params(program: &ProgramId) -> Option<SszWithdrawalsParams> {
+    let repetitions = match program {
+        ProgramId::SSZWithdrawals1x => 1,
+        ProgramId::SSZWithdrawals10x => 10,
+        ProgramId::SSZWithdrawals100x => 100,
+        _ => return None,
+    };
+
+    Some(SszWithdrawalsParams {
+        start_slot: 7855804,
+        end_slot: 7855807,
+        eigenpod_address: [
+            0xe9, 0xcd, 0x14, 0x19, 0xa0, 0x15, 0xdd, 0x05, 0xd4, 0x7f, 0x61, 0x39, 0xf5, 0xb8,
+            0xe8, 0x6b, 0x1e, 0x9e, 0x5c, 0xdd,
+        ],
+        repetitions,
+    })
+}
+
+/// A memory access pattern of the `memory` guest.
+#[derive(Serialize, Clone, Copy)]
+pub enum MemoryWorkload {
+    /// Reads and overwrites random words of a heap array of up to 16 MiB.
+    RandomAccess,
+    /// Copies words back and forth between two buffers of up to 1 MiB.
+    Memcpy,
+    /// Sorts a `Vec` of random words.
+    Sort,
+    /// Inserts random keys into a `BTreeMap`.
+    Btreemap,
+}
+
+impl MemoryWorkload {
+    /// The rough number of user cycles one operation of the workload takes, including its share of
+    /// setting up the data.
+    fn cycles_per_op(&self) -> u64 {
+        match self {
+            MemoryWorkload::RandomAccess => 32,
+            MemoryWorkload::Memcpy => 4,
+            MemoryWorkload::Sort => 300,
+            MemoryWorkload::Btreemap => 1000,
+        }
+    }
+}
+
+/// The input of a memory program: its workload and the number of operations, sized so that the
+/// program runs for roughly its nominal number of cycles, like the loop programs.
+pub fn memory_input(program: &ProgramId) -> Option<(MemoryWorkload, u32)> {
+    let (workload, cycles) = match program {
+        ProgramId::RandomAccess10k => (MemoryWorkload::RandomAccess, 10_000),
+        ProgramId::RandomAccess100k => (MemoryWorkload::RandomAccess, 100_000),
+        ProgramId::RandomAccess1m => (MemoryWorkload::RandomAccess, 1_000_000),
+        ProgramId::RandomAccess3m => (MemoryWorkload::RandomAccess, 3_000_000),
+        ProgramId::RandomAccess10m => (MemoryWorkload::RandomAccess, 10_000_000),
+        ProgramId::RandomAccess30m => (MemoryWorkload::RandomAccess, 30_000_000),
+        ProgramId::RandomAccess100m => (MemoryWorkload::RandomAccess, 100_000_000),
+        ProgramId::RandomAccess300m => (MemoryWorkload::RandomAccess, 300_000_000),
+        ProgramId::Memcpy10k => (MemoryWorkload::Memcpy, 10_000),
+        ProgramId::Memcpy100k => (MemoryWorkload::Memcpy, 100_000),
+        ProgramId::Memcpy1m => (MemoryWorkload::Memcpy, 1_000_000),
+        ProgramId::Memcpy3m => (MemoryWorkload::Memcpy, 3_000_000),
+        ProgramId::Memcpy10m => (MemoryWorkload::Memcpy, 10_000_000),
+        ProgramId::Memcpy30m => (MemoryWorkload::Memcpy, 30_000_000),
+        ProgramId::Memcpy100m => (MemoryWorkload::Memcpy, 100_000_000),
+        ProgramId::Memcpy300m => (MemoryWorkload::Memcpy, 300_000_000),
+        ProgramId::Sort10k => (MemoryWorkload::Sort, 10_000),
+        ProgramId::Sort100k => (MemoryWorkload::Sort, 100_000),
+        ProgramId::Sort1m => (MemoryWorkload::Sort, 1_000_000),
+        ProgramId::Sort3m => (MemoryWorkload::Sort, 3_000_000),
+        ProgramId::Sort10m => (MemoryWorkload::Sort, 10_000_000),
+        ProgramId::Sort30m => (MemoryWorkload::Sort, 30_000_000),
+        ProgramId::Sort100m => (MemoryWorkload::Sort, 100_000_000),
+        ProgramId::Sort300m => (MemoryWorkload::Sort, 300_000_000),
+        ProgramId::Btreemap10k => (MemoryWorkload::Btreemap, 10_000),
+        ProgramId::Btreemap100k => (MemoryWorkload::Btreemap, 100_000),
+        ProgramId::Btreemap1m => (MemoryWorkload::Btreemap, 1_000_000),
+        ProgramId::Btreemap3m => (MemoryWorkload::Btreemap, 3_000_000),
+        ProgramId::Btreemap10m => (MemoryWorkload::Btreemap, 10_000_000),
+        ProgramId::Btreemap30m => (MemoryWorkload::Btreemap, 30_000_000),
+        ProgramId::Btreemap100m => (MemoryWorkload::Btreemap, 100_000_000),
+        ProgramId::Btreemap300m => (MemoryWorkload::Btreemap, 300_000_000),
+        _ => return None,
+    };
+
+    Some((workload, (cycles / workload.cycles_per_op()) as u32))
+}
+
+/// The hashes per second are given by the number of blocks the hash function processes, i.e. its
+/// compression function calls or permutations, over all messages of the `hashed_bytes` the guest
+/// was given.
+pub fn hashes_per_second(
+    args: &EvalArgs,
+    hashed_bytes: Option<u64>,
+    core_compress_duration: Duration,
+) -> Option<f64> {
+    let num_of_bytes = hashed_bytes?;
+    let message_size = hash_message_size(args)? as u64;
+
+    let full_messages = num_of_bytes / message_size;
+    let last_message = num_of_bytes % message_size;
+    let mut hashes_total = full_messages * hash_blocks(&args.program, message_size);
+    if last_message > 0 {
+        hashes_total += hash_blocks(&args.program, last_message);
+    }
+
+    let duration = core_compress_duration.as_secs_f64();
+
+    Some(hashes_total as f64 / duration)
+}
+
+/// The number of blocks the hash function of a hashing program processes to hash a message of
+/// `len` bytes, including its padding.
+fn hash_blocks(program: &ProgramId, len: u64) -> u64 {
+    match program {
+        // Merkle-Damgard padding appends at least 9 bytes.
         ProgramId::Sha256100kb
         | ProgramId::Sha256300kb
         | ProgramId::Sha2561mb
         | ProgramId::Sha2563mb
-        | ProgramId::Sha25610mb => 64,
+        | ProgramId::Sha25610mb
+        | ProgramId::Ripemd160100kb
+        | ProgramId::Ripemd1601mb
+        | ProgramId::Ripemd16010mb => (len + 9).div_ceil(64),
+        // The sponge absorbs 136 bytes per permutation and pads with at least 1 byte.
         ProgramId::Keccak256100kb
         | ProgramId::Keccak256300kb
         | ProgramId::Keccak2561mb
         | ProgramId::Keccak2563mb
-        | ProgramId::Keccak25610mb => 136,
-        _ => return None,
-    };
-
-    let num_of_bytes = hash_input_size_bytes(program).expect("We should have an input size if we have a word size");
-    let hashes_total = num_of_bytes / word_size_bytes;
-
-    let duration = core_compress_duration.as_secs_f64();
-    
-    Some(hashes_total as f64 / duration)
+        | ProgramId::Keccak25610mb => len / 136 + 1,
+        // BLAKE2s only pads the last block.
+        ProgramId::Blake2s100kb | ProgramId::Blake2s1mb | ProgramId::Blake2s10mb => {
+            len.div_ceil(64).max(1)
+        }
+        // BLAKE3 compresses each 64-byte block, plus one parent node per pair of subtrees over
+        // the 1024-byte chunks.
+        ProgramId::Blake3100kb | ProgramId::Blake31mb | ProgramId::Blake310mb => {
+            len.div_ceil(64).max(1) + len.div_ceil(1024).max(1) - 1
+        }
+        // The sponges absorb 3 bytes per BabyBear element and 31 bytes per BN254 element, plus a
+        // padding element, at a rate of 8 and 2 elements per permutation.
+        ProgramId::Poseidon2Babybear10kb
+        | ProgramId::Poseidon2Babybear100kb
+        | ProgramId::Poseidon2Babybear1mb => (len.div_ceil(3) + 1).div_ceil(8),
+        ProgramId::Poseidon2Bn25410kb
+        | ProgramId::Poseidon2Bn254100kb
+        | ProgramId::Poseidon2Bn2541mb => (len.div_ceil(31) + 1).div_ceil(2),
+        _ => unreachable!("{} is not a hashing program", program),
+    }
 }
 
 /// The number of bytes hashed per second
 ///
-/// Given by dividing the number of bytes hashed by the duration of the proving
-pub fn hash_bytes_per_second(program: &ProgramId, core_compress_duration: Duration) -> Option<f64> {
-    let num_of_bytes = hash_input_size_bytes(program)?;
+/// Given by dividing the number of bytes the guest was given by the duration of the proving
+pub fn hash_bytes_per_second(
+    hashed_bytes: Option<u64>,
+    core_compress_duration: Duration,
+) -> Option<f64> {
+    let num_of_bytes = hashed_bytes?;
 
     let num_of_bytes = num_of_bytes as f64;
 
@@ -140,7 +366,8 @@ pub fn hash_bytes_per_second(program: &ProgramId, core_compress_duration: Durati
     Some(num_of_bytes / duration)
 }
 
-/// The number of bytes we pass in as input for each program type
+/// The number of bytes we pass in as input for each program type. Throughput is computed from the
+/// input actually written, which the evaluators check against this size and the guest's commit.
 pub fn hash_input_size_bytes(program: &ProgramId) -> Option<u64> {
     let num_of_bytes = match program {
         ProgramId::Sha256100kb => 102400,
@@ -153,12 +380,50 @@ pub fn hash_input_size_bytes(program: &ProgramId) -> Option<u64> {
         ProgramId::Keccak2561mb => 1048576,
         ProgramId::Keccak2563mb => 1048576 * 3,
         ProgramId::Keccak25610mb => 1048576 * 10,
+        ProgramId::Blake2s100kb => 102400,
+        ProgramId::Blake2s1mb => 1048576,
+        ProgramId::Blake2s10mb => 1048576 * 10,
+        ProgramId::Blake3100kb => 102400,
+        ProgramId::Blake31mb => 1048576,
+        ProgramId::Blake310mb => 1048576 * 10,
+        ProgramId::Ripemd160100kb => 102400,
+        ProgramId::Ripemd1601mb => 1048576,
+        ProgramId::Ripemd16010mb => 1048576 * 10,
+        ProgramId::Poseidon2Babybear10kb => 10240,
+        ProgramId::Poseidon2Babybear100kb => 102400,
+        ProgramId::Poseidon2Babybear1mb => 1048576,
+        ProgramId::Poseidon2Bn25410kb => 10240,
+        ProgramId::Poseidon2Bn254100kb => 102400,
+        ProgramId::Poseidon2Bn2541mb => 1048576,
         _ => return None,
     };
 
     Some(num_of_bytes)
 }
 
+/// The size of each message a hashing program hashes separately.
+pub fn hash_message_size(args: &EvalArgs) -> Option<u32> {
+    let num_of_bytes = hash_input_size_bytes(&args.program)?;
+    Some(args.hash_message_size.unwrap_or(num_of_bytes as u32))
+}
+
+/// The bytes hashed by a hashing program, as recorded in the report.
+pub fn hash_bytes(args: &EvalArgs) -> Option<String> {
+    hash_input_size_bytes(&args.program).map(|_| args.hash_bytes.to_string())
+}
+
+/// The input of a hashing program: the size of each message and the bytes of all messages, either
+/// zeros or drawn from `--seed`.
+pub fn hash_input(args: &EvalArgs) -> Option<(u32, Vec<u8>)> {
+    let num_of_bytes = hash_input_size_bytes(&args.program)?;
+    let mut data = vec![0u8; num_of_bytes as usize];
+    if args.hash_bytes == HashBytes::Random {
+        StdRng::seed_from_u64(args.seed).fill_bytes(&mut data);
+    }
+
+    Some((hash_message_size(args)?, data))
+}
+
 // for now just for RSP
 //pub fn raw_input(program: &ProgramId) -> Option<&[u8]> {
 //    let raw = match program {
@@ -198,27 +463,465 @@ pub fn hash_input_size_bytes(program: &ProgramId) -> Option<u64> {
 //    Some(raw)
 //}
 
+/// The domain separation tag of Ethereum's BLS signatures.
+const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The signatures verified by a signature program.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SignatureBatch {
+    /// The encoded verifying keys. A batch from a repeated signer has a single key.
+    pub keys: Vec<Vec<u8>>,
+    /// Each message with the index of the key that signed it.
+    pub messages: Vec<(u32, Vec<u8>)>,
+    /// The encoded signature of each message, or a single aggregate signature for BLS.
+    pub signatures: Vec<Vec<u8>>,
+}
+
+/// The number of signatures a signature program verifies.
+pub fn signature_count(args: &EvalArgs) -> Option<u32> {
+    let default = match args.program {
+        ProgramId::EDDSAVerify => 100,
+        ProgramId::ECDSAVerify
+        | ProgramId::P256Verify
+        | ProgramId::SchnorrVerify
+        | ProgramId::BLSAggregateVerify => 1,
+        _ => return None,
+    };
+
+    Some(args.signature_count.unwrap_or(default))
+}
+
+/// The signers recorded in the report of a signature program.
+pub fn signers(args: &EvalArgs) -> Option<String> {
+    signature_count(args).map(|_| args.signers.to_string())
+}
+
+/// Signs `signature_count` distinct 32-byte messages for a signature program, with keys and
+/// messages drawn from `--seed`.
+pub fn signature_batch(args: &EvalArgs) -> Option<SignatureBatch> {
+    use k256::ecdsa::signature::Signer;
+
+    let count = signature_count(args)?;
+    let num_keys = match args.signers {
+        Signers::Distinct => count,
+        Signers::Repeated => 1,
+    };
+
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let messages = (0..count)
+        .map(|i| {
+            let mut message = vec![0u8; 32];
+            rng.fill_bytes(&mut message);
+            (i % num_keys, message)
+        })
+        .collect::<Vec<_>>();
+
+    fn sign_each<K>(
+        keys: Vec<K>,
+        messages: Vec<(u32, Vec<u8>)>,
+        encode: impl Fn(&K) -> Vec<u8>,
+        sign: impl Fn(&K, &[u8]) -> Vec<u8>,
+    ) -> SignatureBatch {
+        let signatures = messages.iter().map(|(i, message)| sign(&keys[*i as usize], message));
+        SignatureBatch {
+            keys: keys.iter().map(encode).collect(),
+            signatures: signatures.collect(),
+            messages,
+        }
+    }
+
+    let batch = match args.program {
+        ProgramId::ECDSAVerify => sign_each(
+            (0..num_keys).map(|_| k256::ecdsa::SigningKey::random(&mut rng)).collect(),
+            messages,
+            |key| key.verifying_key().to_encoded_point(true).as_bytes().to_vec(),
+            |key, message| {
+                let signature: k256::ecdsa::Signature = key.sign(message);
+                signature.to_bytes().to_vec()
+            },
+        ),
+        ProgramId::EDDSAVerify => sign_each(
+            (0..num_keys).map(|_| ed25519_dalek::SigningKey::generate(&mut rng)).collect(),
+            messages,
+            |key| key.verifying_key().to_bytes().to_vec(),
+            |key, message| key.sign(message).to_bytes().to_vec(),
+        ),
+        ProgramId::P256Verify => sign_each(
+            (0..num_keys).map(|_| p256::ecdsa::SigningKey::random(&mut rng)).collect(),
+            messages,
+            |key| key.verifying_key().to_encoded_point(true).as_bytes().to_vec(),
+            |key, message| {
+                let signature: p256::ecdsa::Signature = key.sign(message);
+                signature.to_bytes().to_vec()
+            },
+        ),
+        ProgramId::SchnorrVerify => sign_each(
+            (0..num_keys).map(|_| k256::schnorr::SigningKey::random(&mut rng)).collect(),
+            messages,
+            |key| key.verifying_key().to_bytes().to_vec(),
+            |key, message| key.sign(message).to_bytes().to_vec(),
+        ),
+        ProgramId::BLSAggregateVerify => {
+            let keys = (0..num_keys)
+                .map(|_| {
+                    let mut ikm = [0u8; 32];
+                    rng.fill_bytes(&mut ikm);
+                    blst::min_pk::SecretKey::key_gen(&ikm, &[]).unwrap()
+                })
+                .collect::<Vec<_>>();
+            let signatures = messages
+                .iter()
+                .map(|(i, message)| keys[*i as usize].sign(message, BLS_DST, &[]))
+                .collect::<Vec<_>>();
+            let signature = blst::min_pk::AggregateSignature::aggregate(
+                &signatures.iter().collect::<Vec<_>>(),
+                false,
+            )
+            .unwrap();
+            SignatureBatch {
+                keys: keys.iter().map(|key| key.sk_to_pk().compress().to_vec()).collect(),
+                messages,
+                signatures: vec![signature.to_signature().compress().to_vec()],
+            }
+        }
+        _ => unreachable!(),
+    };
+
+    Some(batch)
+}
+
+/// The number of modular multiplications of the `mulmod256` programs.
+const MULMOD256_COUNT: u32 = 100_000;
+
+/// The number of exponentiations of the `modexp` programs.
+const MODEXP_COUNT: u32 = 10;
+
+/// The public exponent the `modexp` programs raise to, as in RSA signature verification.
+const MODEXP_EXPONENT: u32 = 65537;
+
+/// The number of bilinearity checks of the pairing programs.
+const PAIRING_CHECKS: usize = 4;
+
+/// The input of the `mulmod256` programs, with every operand as little-endian words.
+#[derive(Serialize)]
+pub struct Mulmod256Input {
+    pub count: u32,
+    pub a: [u32; 8],
+    pub b: [u32; 8],
+    pub modulus: [u32; 8],
+}
+
+/// The input of the `modexp` programs, with the modulus and base as big-endian bytes.
+#[derive(Serialize)]
+pub struct ModexpInput {
+    pub count: u32,
+    pub modulus: Vec<u8>,
+    pub base: Vec<u8>,
+}
+
+/// The input of a `mulmod256` program, drawn from `--seed`, and the product `a * b^count` modulo
+/// `modulus` it must commit. The modulus is odd and 256 bits wide, and the operands are below it.
+pub fn mulmod256_input(args: &EvalArgs) -> Option<(Mulmod256Input, [u32; 8])> {
+    if !matches!(args.program, ProgramId::Mulmod256 | ProgramId::Mulmod256Baseline) {
+        return None;
+    }
+
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let mut words = || std::array::from_fn::<u32, 8, _>(|_| rng.next_u32());
+    let mut modulus = words();
+    modulus[0] |= 1;
+    modulus[7] |= 1 << 31;
+    let mut a = words();
+    a[7] &= !(1 << 31);
+    let mut b = words();
+    b[7] &= !(1 << 31);
+
+    let n = BigUint::from_slice(&modulus);
+    let product =
+        BigUint::from_slice(&a) * BigUint::from_slice(&b).modpow(&MULMOD256_COUNT.into(), &n) % &n;
+    let mut expected = [0u32; 8];
+    for (word, digit) in expected.iter_mut().zip(product.to_u32_digits()) {
+        *word = digit;
+    }
+
+    Some((Mulmod256Input { count: MULMOD256_COUNT, a, b, modulus }, expected))
+}
+
+/// The input of a `modexp` program, drawn from `--seed`, and the big-endian power it must commit.
+/// The modulus is odd and exactly as wide as the program size, and the base is below it.
+pub fn modexp_input(args: &EvalArgs) -> Option<(ModexpInput, Vec<u8>)> {
+    let bits = match args.program {
+        ProgramId::Modexp2048 | ProgramId::Modexp2048Baseline => 2048,
+        ProgramId::Modexp4096 | ProgramId::Modexp4096Baseline => 4096,
+        _ => return None,
+    };
+
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let mut modulus = vec![0u8; bits / 8];
+    rng.fill_bytes(&mut modulus);
+    modulus[0] |= 0x80;
+    modulus[bits / 8 - 1] |= 1;
+    let mut base = vec![0u8; bits / 8];
+    rng.fill_bytes(&mut base);
+    base[0] &= 0x7f;
+
+    let n = BigUint::from_bytes_be(&modulus);
+    let mut power = BigUint::from_bytes_be(&base);
+    for _ in 0..MODEXP_COUNT {
+        power = power.modpow(&MODEXP_EXPONENT.into(), &n);
+    }
+
+    Some((ModexpInput { count: MODEXP_COUNT, modulus, base }, power.to_bytes_be()))
+}
+
+/// The input of a pairing program: pairs of 64 bytes drawn from `--seed`, which the guest reduces
+/// to the two scalars of each bilinearity check.
+pub fn pairing_scalars(args: &EvalArgs) -> Option<Vec<(Vec<u8>, Vec<u8>)>> {
+    if !matches!(
+        args.program,
+        ProgramId::Bn254Pairing
+            | ProgramId::Bn254PairingBaseline
+            | ProgramId::Bls12381Pairing
+            | ProgramId::Bls12381PairingBaseline
+    ) {
+        return None;
+    }
+
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let mut scalar = || {
+        let mut bytes = vec![0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        bytes
+    };
+
+    Some((0..PAIRING_CHECKS).map(|_| (scalar(), scalar())).collect())
+}
+
+// BN254 Groth16 proof, verifying key and public inputs verified by both the SP1 and RISC Zero
+// builds of `programs/groth`, in the SnarkJS JSON format. Regenerate with
+// `cargo run --release --manifest-path fixtures/groth16/generator/Cargo.toml`.
+pub const GROTH16_PROOF: &str = include_str!("../../fixtures/groth16/proof.json");
+pub const GROTH16_PUBLIC_INPUTS: &str = include_str!("../../fixtures/groth16/public.json");
+pub const GROTH16_VERIFICATION_KEY: &str =
+    include_str!("../../fixtures/groth16/verification_key.json");
 
-pub fn rand_ecdsa_signature() -> (k256::EncodedPoint, Vec<u8>, k256::ecdsa::Signature) {
-    use rand::rngs::OsRng;
-    use k256::ecdsa::{SigningKey, VerifyingKey};
+type G1Bytes = [[u8; 32]; 2];
+type G2Bytes = [[[u8; 32]; 2]; 2];
 
-    let mut signing_key = SigningKey::random(&mut OsRng);
-    let verifying_key = VerifyingKey::from(&signing_key);
+/// The Groth16 fixture with every field element encoded as 32 big-endian bytes, in the layout
+/// read by the SP1 build of `programs/groth`. G2 coordinates are ordered `(c0, c1)`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Groth16FixtureBytes {
+    pub a: G1Bytes,
+    pub b: G2Bytes,
+    pub c: G1Bytes,
+    pub alpha: G1Bytes,
+    pub beta: G2Bytes,
+    pub gamma: G2Bytes,
+    pub delta: G2Bytes,
+    pub ic: Vec<G1Bytes>,
+    pub public_inputs: Vec<[u8; 32]>,
+}
+
+pub fn groth16_fixture_bytes() -> Groth16FixtureBytes {
+    use serde_json::Value;
+
+    fn scalar(value: &Value) -> [u8; 32] {
+        let decimal = value.as_str().unwrap();
+        let bytes = num_bigint::BigUint::parse_bytes(decimal.as_bytes(), 10).unwrap().to_bytes_be();
+        assert!(bytes.len() <= 32, "field element does not fit in 32 bytes");
+
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+        out
+    }
+    fn g1(value: &Value) -> G1Bytes {
+        [scalar(&value[0]), scalar(&value[1])]
+    }
+    fn g2(value: &Value) -> G2Bytes {
+        [[scalar(&value[0][0]), scalar(&value[0][1])], [scalar(&value[1][0]), scalar(&value[1][1])]]
+    }
+
+    let proof: Value = serde_json::from_str(GROTH16_PROOF).unwrap();
+    let vk: Value = serde_json::from_str(GROTH16_VERIFICATION_KEY).unwrap();
+    let public_inputs: Value = serde_json::from_str(GROTH16_PUBLIC_INPUTS).unwrap();
+
+    Groth16FixtureBytes {
+        a: g1(&proof["pi_a"]),
+        b: g2(&proof["pi_b"]),
+        c: g1(&proof["pi_c"]),
+        alpha: g1(&vk["vk_alpha_1"]),
+        beta: g2(&vk["vk_beta_2"]),
+        gamma: g2(&vk["vk_gamma_2"]),
+        delta: g2(&vk["vk_delta_2"]),
+        ic: vk["IC"].as_array().unwrap().iter().map(g1).collect(),
+        public_inputs: public_inputs.as_array().unwrap().iter().map(scalar).collect(),
+    }
+}
+
+/// Light client options read by the tendermint guests, followed by the trusted and the untrusted
+/// [`EncodedLightBlock`].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TendermintVerifierInput {
+    pub trust_threshold: (u64, u64),
+    pub trusting_period_secs: u64,
+    pub clock_drift_secs: u64,
+    pub peer_id: [u8; 20],
+    /// Whether the light blocks are CometBFT RPC JSON rather than protobuf.
+    pub json: bool,
+}
+
+/// The signed header, validator set and next validator set of a light block.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EncodedLightBlock {
+    pub signed_header: Vec<u8>,
+    pub validators: Vec<u8>,
+    pub next_validators: Vec<u8>,
+}
+
+/// Parses a tendermint trust threshold given as `numerator/denominator`.
+pub fn parse_trust_threshold(threshold: &str) -> Result<(u64, u64), String> {
+    threshold
+        .split_once('/')
+        .and_then(|(n, d)| Some((n.trim().parse().ok()?, d.trim().parse().ok()?)))
+        .filter(|&(_, denominator)| denominator > 0)
+        .ok_or_else(|| "the trust threshold must be given as `numerator/denominator`".to_string())
+}
+
+/// Builds the tendermint guest input from the light blocks in `fixtures/tendermint/`.
+pub fn tendermint_input(
+    args: &EvalArgs,
+) -> Result<(TendermintVerifierInput, EncodedLightBlock, EncodedLightBlock), EvalError> {
+    // The trusting periods cover the time between the trusted block and the verification time.
+    let (trusted, untrusted, trusting_period_secs) = match args.program {
+        ProgramId::Tendermint => ("1", "2", 500),
+        ProgramId::TendermintSkipping => ("skipping-1", "skipping-2", 14 * 24 * 60 * 60),
+        _ => return Err(EvalError::Prove(format!("{} is not a tendermint program", args.program))),
+    };
+
+    let input = TendermintVerifierInput {
+        trust_threshold: args.tendermint_trust_threshold,
+        trusting_period_secs: args.tendermint_trusting_period_secs.unwrap_or(trusting_period_secs),
+        clock_drift_secs: args.tendermint_clock_drift_secs,
+        peer_id: [
+            0x72, 0x6b, 0xc8, 0xd2, 0x60, 0x38, 0x7c, 0xf5, 0x6e, 0xcf, 0xad, 0x3a, 0x6b, 0xf6,
+            0xfe, 0xcd, 0x90, 0x3e, 0x18, 0xa2,
+        ],
+        json: args.tendermint_encoding == TendermintEncoding::Json,
+    };
 
-    let message = b"Hello, world!";
-    let signature = signing_key.sign(message);
+    let encoding = &args.tendermint_encoding;
+    Ok((input, encoded_light_block(trusted, encoding)?, encoded_light_block(untrusted, encoding)?))
+}
+
+fn encoded_light_block(
+    name: &str,
+    encoding: &TendermintEncoding,
+) -> Result<EncodedLightBlock, EvalError> {
+    use tendermint::{block::signed_header::SignedHeader, validator};
+    use tendermint_proto::{
+        v0_37::types::{SignedHeader as RawSignedHeader, ValidatorSet as RawValidatorSet},
+        Protobuf,
+    };
 
-    (verifying_key.to_encoded_point(true), message.to_vec(), signature)
+    #[derive(Deserialize)]
+    struct Response<T> {
+        result: T,
+    }
+    #[derive(Deserialize)]
+    struct SignedHeaderResult {
+        signed_header: SignedHeader,
+    }
+    #[derive(Deserialize)]
+    struct ValidatorsResult {
+        validators: Vec<validator::Info>,
+    }
+
+    let dir = env::current_dir().map_err(EvalError::prove)?.join("fixtures/tendermint").join(name);
+    let read = |file: &str| {
+        let path = dir.join(file);
+        fs::read(&path)
+            .map_err(|err| EvalError::Prove(format!("failed to read {}: {}", path.display(), err)))
+    };
+    let signed_header = read("signed_header.json")?;
+    let validators = read("validators.json")?;
+    let next_validators = read("next_validators.json")?;
+    if *encoding == TendermintEncoding::Json {
+        return Ok(EncodedLightBlock { signed_header, validators, next_validators });
+    }
+
+    let signed_header = serde_json::from_slice::<Response<SignedHeaderResult>>(&signed_header)
+        .map_err(EvalError::serialize)?;
+    let validator_set = |json: &[u8]| {
+        let validators = serde_json::from_slice::<Response<ValidatorsResult>>(json)
+            .map_err(EvalError::serialize)?;
+        let set = validator::Set::new(validators.result.validators, None);
+        Ok::<_, EvalError>(Protobuf::<RawValidatorSet>::encode_vec(set))
+    };
+
+    Ok(EncodedLightBlock {
+        signed_header: Protobuf::<RawSignedHeader>::encode_vec(signed_header.result.signed_header),
+        validators: validator_set(&validators)?,
+        next_validators: validator_set(&next_validators)?,
+    })
+}
+
+/// The light block encoding recorded in the report of a tendermint program.
+pub fn tendermint_encoding(args: &EvalArgs) -> Option<String> {
+    match args.program {
+        ProgramId::Tendermint | ProgramId::TendermintSkipping => {
+            Some(args.tendermint_encoding.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The state committed by the Helios programs.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct HeliosOutputs {
+    pub prev_header: [u8; 32],
+    pub prev_head: u64,
+    pub header: [u8; 32],
+    pub sync_committee_hash: [u8; 32],
+    pub next_sync_committee_hash: [u8; 32],
+    pub head: u64,
 }
 
-pub fn rand_eddsa_signature() -> (ed25519_dalek::VerifyingKey, Vec<u8>, ed25519_dalek::Signature) {
-    use rand::rngs::OsRng;
-    use ed25519_dalek::{SigningKey, Signer};
+/// The proof inputs of a Helios program and the state it must commit, as precomputed by
+/// `fixtures/helios/generator`.
+pub fn helios_fixture(program: &ProgramId) -> Option<(&'static [u8], HeliosOutputs)> {
+    let (name, input): (&str, &'static [u8]) = match program {
+        ProgramId::Helios0 => {
+            ("proof_inputs_0.cbor", include_bytes!("../../fixtures/helios/proof_inputs_0.cbor"))
+        }
+        ProgramId::Helios1 => {
+            ("proof_inputs.cbor", include_bytes!("../../fixtures/helios/proof_inputs.cbor"))
+        }
+        ProgramId::Helios4 => {
+            ("proof_inputs_4.cbor", include_bytes!("../../fixtures/helios/proof_inputs_4.cbor"))
+        }
+        _ => return None,
+    };
 
-    let signing_key = SigningKey::generate(&mut OsRng);
-    let message = b"Hello, world!";
-    let signature = signing_key.sign(message);
+    let outputs: serde_json::Value =
+        serde_json::from_str(include_str!("../../fixtures/helios/outputs.json")).unwrap();
+    let outputs = &outputs[name];
+    let root = |field: &str| {
+        let hex = outputs[field].as_str().unwrap().trim_start_matches("0x");
+        let mut root = [0u8; 32];
+        for (i, byte) in root.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        root
+    };
 
-    (signing_key.verifying_key(), message.to_vec(), signature)
+    let expected = HeliosOutputs {
+        prev_header: root("prev_header"),
+        prev_head: outputs["prev_head"].as_u64().unwrap(),
+        header: root("header"),
+        sync_committee_hash: root("sync_committee_hash"),
+        next_sync_committee_hash: root("next_sync_committee_hash"),
+        head: outputs["head"].as_u64().unwrap(),
+    };
+    Some((input, expected))
 }
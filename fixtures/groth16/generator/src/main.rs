@@ -0,0 +1,129 @@
+//! Generates the BN254 Groth16 fixture shared by the SP1 and RISC Zero builds of `programs/groth`.
+//!
+//! The proof, verifying key and public inputs are written in the SnarkJS JSON format to
+//! `fixtures/groth16/{proof,verification_key,public}.json`. Setup and proving use a fixed seed, so
+//! rerunning the generator reproduces the checked-in files:
+//!
+//! ```sh
+//! cargo run --release --manifest-path fixtures/groth16/generator/Cargo.toml
+//! ```
+
+use std::{fs, path::PathBuf};
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::pairing::Pairing;
+use ark_ff::{Field, PrimeField};
+use ark_groth16::Groth16;
+use ark_relations::{
+    lc,
+    r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+};
+use ark_snark::SNARK;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use serde_json::{json, Value};
+
+/// The number of public inputs of the fixture circuit.
+const NUM_PUBLIC_INPUTS: u64 = 8;
+
+/// The seed used for the trusted setup and the proof.
+const SEED: u64 = 0;
+
+/// Proves knowledge of `x_i` such that `x_i^3 + x_i + 5 = y_i` for every public `y_i`.
+#[derive(Clone)]
+struct CubicCircuit {
+    xs: Vec<Option<Fr>>,
+}
+
+fn cubic(x: Fr) -> Fr {
+    x * x * x + x + Fr::from(5u64)
+}
+
+impl ConstraintSynthesizer<Fr> for CubicCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        for x in self.xs {
+            let x_value = x.ok_or(SynthesisError::AssignmentMissing);
+            let x_var = cs.new_witness_variable(|| x_value)?;
+            let x_squared = cs.new_witness_variable(|| x_value.map(|x| x.square()))?;
+            let x_cubed = cs.new_witness_variable(|| x_value.map(|x| x.square() * x))?;
+            let y = cs.new_input_variable(|| x_value.map(cubic))?;
+
+            cs.enforce_constraint(lc!() + x_var, lc!() + x_var, lc!() + x_squared)?;
+            cs.enforce_constraint(lc!() + x_squared, lc!() + x_var, lc!() + x_cubed)?;
+            cs.enforce_constraint(
+                lc!() + x_cubed + x_var + (Fr::from(5u64), ark_relations::r1cs::Variable::One),
+                lc!() + ark_relations::r1cs::Variable::One,
+                lc!() + y,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn fq(value: Fq) -> Value {
+    json!(value.into_bigint().to_string())
+}
+
+fn fq2(value: Fq2) -> Value {
+    json!([fq(value.c0), fq(value.c1)])
+}
+
+fn g1(point: G1Affine) -> Value {
+    json!([fq(point.x), fq(point.y), "1"])
+}
+
+fn g2(point: G2Affine) -> Value {
+    json!([fq2(point.x), fq2(point.y), ["1", "0"]])
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    let xs = (1..=NUM_PUBLIC_INPUTS).map(Fr::from).collect::<Vec<_>>();
+    let public_inputs = xs.iter().copied().map(cubic).collect::<Vec<_>>();
+
+    let setup_circuit = CubicCircuit { xs: vec![None; xs.len()] };
+    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(setup_circuit, &mut rng).unwrap();
+
+    let circuit = CubicCircuit { xs: xs.into_iter().map(Some).collect() };
+    let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+    assert!(Groth16::<Bn254>::verify(&vk, &public_inputs, &proof).unwrap());
+
+    let alpha_beta = Bn254::pairing(vk.alpha_g1, vk.beta_g2).0;
+
+    let proof_json = json!({
+        "pi_a": g1(proof.a),
+        "pi_b": g2(proof.b),
+        "pi_c": g1(proof.c),
+        "protocol": "groth16",
+        "curve": "bn128",
+    });
+    let verification_key_json = json!({
+        "protocol": "groth16",
+        "curve": "bn128",
+        "nPublic": public_inputs.len(),
+        "vk_alpha_1": g1(vk.alpha_g1),
+        "vk_beta_2": g2(vk.beta_g2),
+        "vk_gamma_2": g2(vk.gamma_g2),
+        "vk_delta_2": g2(vk.delta_g2),
+        "vk_alphabeta_12": [
+            [fq2(alpha_beta.c0.c0), fq2(alpha_beta.c0.c1), fq2(alpha_beta.c0.c2)],
+            [fq2(alpha_beta.c1.c0), fq2(alpha_beta.c1.c1), fq2(alpha_beta.c1.c2)],
+        ],
+        "IC": vk.gamma_abc_g1.iter().copied().map(g1).collect::<Vec<_>>(),
+    });
+    let public_json = public_inputs
+        .iter()
+        .map(|input| json!(input.into_bigint().to_string()))
+        .collect::<Vec<_>>();
+
+    let fixtures_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    for (name, value) in [
+        ("proof.json", proof_json),
+        ("verification_key.json", verification_key_json),
+        ("public.json", json!(public_json)),
+    ] {
+        let path = fixtures_dir.join(name);
+        fs::write(&path, serde_json::to_string_pretty(&value).unwrap() + "\n").unwrap();
+        println!("wrote {}", path.display());
+    }
+}
@@ -0,0 +1,740 @@
+//! Generates the Helios proof inputs with 0 and 4 sync committee updates, and the state each
+//! Helios program is expected to commit.
+//!
+//! `proof_inputs.cbor` is real Sepolia data: a store in sync committee period 796, one update that
+//! carries the next sync committee, and a finality update. `proof_inputs_0.cbor` is the same input
+//! with the update removed. `proof_inputs_4.cbor` is a synthetic chain that starts in period 796
+//! and catches up over 4 updates, rotating the sync committee at every period boundary. Its keys
+//! are derived from fixed seeds and its headers and states are filler, but every header, branch
+//! and aggregate signature is laid out and signed as on mainnet.
+//!
+//! Every input is replayed through the light client sync protocol to check it and to compute the
+//! committed state, which is written to `outputs.json`. Rerunning the generator reproduces the
+//! checked-in files:
+//!
+//! ```sh
+//! cargo run --release --manifest-path fixtures/helios/generator/Cargo.toml
+//! ```
+
+use std::{fmt, fs, path::PathBuf};
+
+use blst::{
+    min_pk::{AggregatePublicKey, AggregateSignature, PublicKey, SecretKey, Signature},
+    BLST_ERROR,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+type Root = [u8; 32];
+
+const SLOTS_PER_EPOCH: u64 = 32;
+const SLOTS_PER_PERIOD: u64 = 8192;
+const SYNC_COMMITTEE_SIZE: usize = 512;
+const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Generalized indexes of the light client proofs (Deneb).
+const EXECUTION_PAYLOAD_GINDEX: u64 = 25;
+const FINALIZED_ROOT_GINDEX: u64 = 105;
+const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+
+/// The number of sync committee updates in the synthetic chain, and its first period.
+const CATCH_UP_UPDATES: u64 = 4;
+const CATCH_UP_START_PERIOD: u64 = 796;
+
+/// Synthetic sync committee members with `index % 16 == 5` do not sign, so 480 of 512 take part.
+fn participates(index: usize) -> bool {
+    index % 16 != 5
+}
+
+// Mirrors of the Helios input types, in their CBOR encoding: roots and addresses are byte strings,
+// integers inside headers are decimal strings, and keys, signatures and bitvectors are hex strings.
+
+#[derive(Clone, PartialEq)]
+struct Bytes(Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> de::Visitor<'de> for BytesVisitor {
+            type Value = Bytes;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a byte string")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Bytes, E> {
+                Ok(Bytes(v.to_vec()))
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+impl Bytes {
+    fn root(&self) -> Root {
+        self.0.as_slice().try_into().expect("expected 32 bytes")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+struct Quoted(#[serde(with = "quoted")] u64);
+
+mod quoted {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct BeaconBlockHeader {
+    slot: Quoted,
+    proposer_index: Quoted,
+    parent_root: Bytes,
+    state_root: Bytes,
+    body_root: Bytes,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct ExecutionPayloadHeader {
+    parent_hash: Bytes,
+    fee_recipient: Bytes,
+    state_root: Bytes,
+    receipts_root: Bytes,
+    logs_bloom: String,
+    prev_randao: Bytes,
+    block_number: Quoted,
+    gas_limit: Quoted,
+    gas_used: Quoted,
+    timestamp: Quoted,
+    extra_data: String,
+    base_fee_per_gas: String,
+    block_hash: Bytes,
+    transactions_root: Bytes,
+    withdrawals_root: Bytes,
+    blob_gas_used: Quoted,
+    excess_blob_gas: Quoted,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct LightClientHeader {
+    beacon: BeaconBlockHeader,
+    execution: ExecutionPayloadHeader,
+    execution_branch: Vec<Bytes>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct SyncCommittee {
+    pubkeys: Vec<String>,
+    aggregate_pubkey: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct SyncAggregate {
+    sync_committee_bits: String,
+    sync_committee_signature: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct Update {
+    attested_header: LightClientHeader,
+    next_sync_committee: SyncCommittee,
+    next_sync_committee_branch: Vec<Bytes>,
+    finalized_header: LightClientHeader,
+    finality_branch: Vec<Bytes>,
+    sync_aggregate: SyncAggregate,
+    signature_slot: Quoted,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct FinalityUpdate {
+    attested_header: LightClientHeader,
+    finalized_header: LightClientHeader,
+    finality_branch: Vec<Bytes>,
+    sync_aggregate: SyncAggregate,
+    signature_slot: Quoted,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct LightClientStore {
+    finalized_header: LightClientHeader,
+    current_sync_committee: SyncCommittee,
+    next_sync_committee: Option<SyncCommittee>,
+    optimistic_header: LightClientHeader,
+    previous_max_active_participants: u64,
+    current_max_active_participants: u64,
+    best_valid_update: Option<Update>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct Fork {
+    epoch: u64,
+    fork_version: Bytes,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct Forks {
+    genesis: Fork,
+    altair: Fork,
+    bellatrix: Fork,
+    capella: Fork,
+    deneb: Fork,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct ProofInputs {
+    sync_committee_updates: Vec<Update>,
+    finality_update: FinalityUpdate,
+    expected_current_slot: u64,
+    store: LightClientStore,
+    genesis_root: Bytes,
+    forks: Forks,
+}
+
+// SSZ merkleization.
+
+fn hash(left: &[u8], right: &[u8]) -> Root {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// A deterministic stand-in for a root nothing opens.
+fn filler(label: &str, slot: u64) -> Root {
+    let mut hasher = Sha256::new();
+    hasher.update(label.as_bytes());
+    hasher.update(slot.to_le_bytes());
+    hasher.finalize().into()
+}
+
+fn uint(value: u128) -> Root {
+    let mut chunk = [0u8; 32];
+    chunk[..16].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+/// Packs bytes into zero-padded 32-byte chunks.
+fn chunks(bytes: &[u8]) -> Vec<Root> {
+    bytes
+        .chunks(32)
+        .map(|bytes| {
+            let mut chunk = [0u8; 32];
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            chunk
+        })
+        .collect()
+}
+
+/// The layers of a merkle tree over `leaves`, padded with zero chunks to `width` leaves.
+fn layers(leaves: &[Root], width: usize) -> Vec<Vec<Root>> {
+    assert!(width.is_power_of_two() && leaves.len() <= width);
+    let mut layer = leaves.to_vec();
+    layer.resize(width, [0u8; 32]);
+    let mut layers = vec![layer];
+    while layers.last().unwrap().len() > 1 {
+        let next = layers.last().unwrap().chunks(2).map(|pair| hash(&pair[0], &pair[1])).collect();
+        layers.push(next);
+    }
+    layers
+}
+
+fn merkleize(leaves: &[Root], width: usize) -> Root {
+    layers(leaves, width).last().unwrap()[0]
+}
+
+/// The sibling nodes from the leaf at `index` up to the root.
+fn branch(leaves: &[Root], width: usize, index: usize) -> Vec<Root> {
+    let layers = layers(leaves, width);
+    (0..layers.len() - 1).map(|height| layers[height][(index >> height) ^ 1]).collect()
+}
+
+fn is_valid_merkle_branch(leaf: Root, branch: &[Bytes], gindex: u64) -> Option<Root> {
+    let depth = 63 - gindex.leading_zeros();
+    if branch.len() != depth as usize {
+        return None;
+    }
+    let mut node = leaf;
+    for (height, sibling) in branch.iter().enumerate() {
+        node = if (gindex >> height) & 1 == 1 {
+            hash(&sibling.0, &node)
+        } else {
+            hash(&node, &sibling.0)
+        };
+    }
+    Some(node)
+}
+
+fn beacon_header_root(header: &BeaconBlockHeader) -> Root {
+    let fields = [
+        uint(header.slot.0 as u128),
+        uint(header.proposer_index.0 as u128),
+        header.parent_root.root(),
+        header.state_root.root(),
+        header.body_root.root(),
+    ];
+    merkleize(&fields, 8)
+}
+
+fn execution_header_root(header: &ExecutionPayloadHeader) -> Root {
+    let logs_bloom = hex_decode(&header.logs_bloom);
+    let extra_data = hex_decode(&header.extra_data);
+    let fields = [
+        header.parent_hash.root(),
+        chunks(&header.fee_recipient.0)[0],
+        header.state_root.root(),
+        header.receipts_root.root(),
+        merkleize(&chunks(&logs_bloom), 8),
+        header.prev_randao.root(),
+        uint(header.block_number.0 as u128),
+        uint(header.gas_limit.0 as u128),
+        uint(header.gas_used.0 as u128),
+        uint(header.timestamp.0 as u128),
+        hash(&merkleize(&chunks(&extra_data), 1), &uint(extra_data.len() as u128)),
+        uint(header.base_fee_per_gas.parse().unwrap()),
+        header.block_hash.root(),
+        header.transactions_root.root(),
+        header.withdrawals_root.root(),
+        uint(header.blob_gas_used.0 as u128),
+        uint(header.excess_blob_gas.0 as u128),
+    ];
+    merkleize(&fields, 32)
+}
+
+fn pubkey_root(pubkey: &str) -> Root {
+    let chunks = chunks(&hex_decode(pubkey));
+    hash(&chunks[0], &chunks[1])
+}
+
+fn sync_committee_root(committee: &SyncCommittee) -> Root {
+    let pubkeys = committee.pubkeys.iter().map(|pubkey| pubkey_root(pubkey)).collect::<Vec<_>>();
+    hash(&merkleize(&pubkeys, SYNC_COMMITTEE_SIZE), &pubkey_root(&committee.aggregate_pubkey))
+}
+
+fn hex_decode(value: &str) -> Vec<u8> {
+    hex::decode(value.strip_prefix("0x").unwrap()).unwrap()
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn period(slot: u64) -> u64 {
+    slot / SLOTS_PER_PERIOD
+}
+
+// Signing.
+
+fn signing_root(header: &BeaconBlockHeader, signature_slot: u64, inputs: &ProofInputs) -> Root {
+    let forks = &inputs.forks;
+    let epoch = signature_slot.saturating_sub(1) / SLOTS_PER_EPOCH;
+    let fork_version = [&forks.deneb, &forks.capella, &forks.bellatrix, &forks.altair]
+        .into_iter()
+        .find(|fork| epoch >= fork.epoch)
+        .unwrap_or(&forks.genesis)
+        .fork_version
+        .0
+        .clone();
+    let fork_data_root = hash(&chunks(&fork_version)[0], &inputs.genesis_root.0);
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    hash(&beacon_header_root(header), &domain)
+}
+
+fn participation(aggregate: &SyncAggregate) -> Vec<bool> {
+    let bits = hex_decode(&aggregate.sync_committee_bits);
+    (0..SYNC_COMMITTEE_SIZE).map(|i| (bits[i / 8] >> (i % 8)) & 1 == 1).collect()
+}
+
+fn verify_sync_aggregate(
+    committee: &SyncCommittee,
+    aggregate: &SyncAggregate,
+    message: &Root,
+) -> usize {
+    let participation = participation(aggregate);
+    let pubkeys = committee
+        .pubkeys
+        .iter()
+        .zip(&participation)
+        .filter(|(_, participates)| **participates)
+        .map(|(pubkey, _)| PublicKey::from_bytes(&hex_decode(pubkey)).unwrap())
+        .collect::<Vec<_>>();
+    let pubkeys = pubkeys.iter().collect::<Vec<_>>();
+    let pubkey = AggregatePublicKey::aggregate(&pubkeys, true).unwrap().to_public_key();
+    let signature =
+        Signature::from_bytes(&hex_decode(&aggregate.sync_committee_signature)).unwrap();
+    let result = signature.verify(true, message, BLS_DST, &[], &pubkey, true);
+    assert_eq!(result, BLST_ERROR::BLST_SUCCESS, "invalid sync committee signature");
+    pubkeys.len()
+}
+
+// The light client sync protocol, as run by the guests.
+
+/// The state committed by the Helios programs.
+struct Outputs {
+    prev_header: Root,
+    prev_head: u64,
+    header: Root,
+    sync_committee_hash: Root,
+    next_sync_committee_hash: Root,
+    head: u64,
+}
+
+struct GenericUpdate<'a> {
+    attested_header: &'a LightClientHeader,
+    next_sync_committee: Option<(&'a SyncCommittee, &'a [Bytes])>,
+    finalized_header: &'a LightClientHeader,
+    finality_branch: &'a [Bytes],
+    sync_aggregate: &'a SyncAggregate,
+    signature_slot: u64,
+}
+
+fn check_header(header: &LightClientHeader) {
+    let root = is_valid_merkle_branch(
+        execution_header_root(&header.execution),
+        &header.execution_branch,
+        EXECUTION_PAYLOAD_GINDEX,
+    );
+    assert_eq!(root, Some(header.beacon.body_root.root()), "invalid execution branch");
+}
+
+fn process_update(store: &mut LightClientStore, update: GenericUpdate, inputs: &ProofInputs) {
+    let attested_slot = update.attested_header.beacon.slot.0;
+    let finalized_slot = update.finalized_header.beacon.slot.0;
+    assert!(inputs.expected_current_slot >= update.signature_slot);
+    assert!(update.signature_slot > attested_slot && attested_slot >= finalized_slot);
+
+    let store_period = period(store.finalized_header.beacon.slot.0);
+    let signature_period = period(update.signature_slot);
+    match store.next_sync_committee {
+        Some(_) => {
+            assert!(signature_period == store_period || signature_period == store_period + 1)
+        }
+        None => assert_eq!(signature_period, store_period),
+    }
+    let has_next_sync_committee = store.next_sync_committee.is_none()
+        && update.next_sync_committee.is_some()
+        && period(attested_slot) == store_period;
+    assert!(attested_slot > store.finalized_header.beacon.slot.0 || has_next_sync_committee);
+
+    check_header(update.attested_header);
+    check_header(update.finalized_header);
+    let state_root = update.attested_header.beacon.state_root.root();
+    let root = is_valid_merkle_branch(
+        beacon_header_root(&update.finalized_header.beacon),
+        update.finality_branch,
+        FINALIZED_ROOT_GINDEX,
+    );
+    assert_eq!(root, Some(state_root), "invalid finality branch");
+    if let Some((committee, branch)) = update.next_sync_committee {
+        let root = is_valid_merkle_branch(
+            sync_committee_root(committee),
+            branch,
+            NEXT_SYNC_COMMITTEE_GINDEX,
+        );
+        assert_eq!(root, Some(state_root), "invalid next sync committee branch");
+    }
+
+    let committee = if signature_period == store_period {
+        &store.current_sync_committee
+    } else {
+        store.next_sync_committee.as_ref().unwrap()
+    };
+    let message = signing_root(&update.attested_header.beacon, update.signature_slot, inputs);
+    let participants = verify_sync_aggregate(committee, update.sync_aggregate, &message);
+
+    let has_finalized_next_sync_committee = store.next_sync_committee.is_none()
+        && update.next_sync_committee.is_some()
+        && period(finalized_slot) == period(attested_slot);
+    let is_newer = finalized_slot > store.finalized_header.beacon.slot.0;
+    if participants * 3 >= SYNC_COMMITTEE_SIZE * 2
+        && (is_newer || has_finalized_next_sync_committee)
+    {
+        if store.next_sync_committee.is_none() {
+            assert_eq!(period(finalized_slot), store_period);
+            store.next_sync_committee = update.next_sync_committee.map(|(c, _)| c.clone());
+        } else if period(finalized_slot) == store_period + 1 {
+            store.current_sync_committee = store.next_sync_committee.take().unwrap();
+            store.next_sync_committee = update.next_sync_committee.map(|(c, _)| c.clone());
+        }
+        if is_newer {
+            store.finalized_header = update.finalized_header.clone();
+        }
+    }
+}
+
+fn replay(inputs: &ProofInputs) -> Outputs {
+    let mut store = inputs.store.clone();
+    let prev_header = beacon_header_root(&store.finalized_header.beacon);
+    let prev_head = store.finalized_header.beacon.slot.0;
+
+    for update in &inputs.sync_committee_updates {
+        let update = GenericUpdate {
+            attested_header: &update.attested_header,
+            next_sync_committee: Some((
+                &update.next_sync_committee,
+                &update.next_sync_committee_branch,
+            )),
+            finalized_header: &update.finalized_header,
+            finality_branch: &update.finality_branch,
+            sync_aggregate: &update.sync_aggregate,
+            signature_slot: update.signature_slot.0,
+        };
+        process_update(&mut store, update, inputs);
+    }
+    let update = &inputs.finality_update;
+    let update = GenericUpdate {
+        attested_header: &update.attested_header,
+        next_sync_committee: None,
+        finalized_header: &update.finalized_header,
+        finality_branch: &update.finality_branch,
+        sync_aggregate: &update.sync_aggregate,
+        signature_slot: update.signature_slot.0,
+    };
+    process_update(&mut store, update, inputs);
+
+    Outputs {
+        prev_header,
+        prev_head,
+        header: beacon_header_root(&store.finalized_header.beacon),
+        sync_committee_hash: sync_committee_root(&store.current_sync_committee),
+        next_sync_committee_hash: store
+            .next_sync_committee
+            .as_ref()
+            .map(sync_committee_root)
+            .unwrap_or_default(),
+        head: store.finalized_header.beacon.slot.0,
+    }
+}
+
+// The synthetic catch-up chain.
+
+struct Committee {
+    secret_keys: Vec<SecretKey>,
+    committee: SyncCommittee,
+}
+
+impl Committee {
+    fn new(period: u64) -> Self {
+        let secret_keys = (0..SYNC_COMMITTEE_SIZE as u64)
+            .map(|index| {
+                let mut hasher = Sha256::new();
+                hasher.update(b"helios-fixture");
+                hasher.update(period.to_le_bytes());
+                hasher.update(index.to_le_bytes());
+                let ikm: Root = hasher.finalize().into();
+                SecretKey::key_gen(&ikm, &[]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let pubkeys = secret_keys.iter().map(|key| key.sk_to_pk()).collect::<Vec<_>>();
+        let aggregate_pubkey =
+            AggregatePublicKey::aggregate(&pubkeys.iter().collect::<Vec<_>>(), false).unwrap();
+        let committee = SyncCommittee {
+            pubkeys: pubkeys.iter().map(|key| hex_encode(&key.compress())).collect(),
+            aggregate_pubkey: hex_encode(&aggregate_pubkey.to_public_key().compress()),
+        };
+        Self { secret_keys, committee }
+    }
+
+    fn sign(&self, message: &Root) -> SyncAggregate {
+        let mut bits = [0u8; SYNC_COMMITTEE_SIZE / 8];
+        let mut signatures = Vec::new();
+        for (index, key) in self.secret_keys.iter().enumerate() {
+            if participates(index) {
+                bits[index / 8] |= 1 << (index % 8);
+                signatures.push(key.sign(message, BLS_DST, &[]));
+            }
+        }
+        let signature =
+            AggregateSignature::aggregate(&signatures.iter().collect::<Vec<_>>(), false).unwrap();
+        SyncAggregate {
+            sync_committee_bits: hex_encode(&bits),
+            sync_committee_signature: hex_encode(&signature.to_signature().compress()),
+        }
+    }
+}
+
+/// A header at `slot` with the given state root, based on the execution payload of `template`.
+fn header(slot: u64, state_root: Root, template: &LightClientHeader) -> LightClientHeader {
+    let template_slot = template.beacon.slot.0;
+    let template = &template.execution;
+    let execution = ExecutionPayloadHeader {
+        parent_hash: Bytes(filler("parent_hash", slot).to_vec()),
+        state_root: Bytes(filler("execution_state_root", slot).to_vec()),
+        receipts_root: Bytes(filler("receipts_root", slot).to_vec()),
+        prev_randao: Bytes(filler("prev_randao", slot).to_vec()),
+        block_number: Quoted(template.block_number.0 + slot - template_slot),
+        timestamp: Quoted(template.timestamp.0 + (slot - template_slot) * 12),
+        block_hash: Bytes(filler("block_hash", slot).to_vec()),
+        transactions_root: Bytes(filler("transactions_root", slot).to_vec()),
+        withdrawals_root: Bytes(filler("withdrawals_root", slot).to_vec()),
+        ..template.clone()
+    };
+    let mut body = (0..16).map(|i| filler("body", slot * 16 + i)).collect::<Vec<_>>();
+    body[EXECUTION_PAYLOAD_GINDEX as usize - 16] = execution_header_root(&execution);
+    let execution_branch = branch(&body, 16, EXECUTION_PAYLOAD_GINDEX as usize - 16);
+
+    LightClientHeader {
+        beacon: BeaconBlockHeader {
+            slot: Quoted(slot),
+            proposer_index: Quoted(filler("proposer_index", slot)[0] as u64),
+            parent_root: Bytes(filler("parent_root", slot).to_vec()),
+            state_root: Bytes(state_root.to_vec()),
+            body_root: Bytes(merkleize(&body, 16).to_vec()),
+        },
+        execution,
+        execution_branch: execution_branch.into_iter().map(|node| Bytes(node.to_vec())).collect(),
+    }
+}
+
+/// An attested header whose state finalizes `finalized` and holds `next_sync_committee`, with the
+/// finality and next sync committee branches of that state.
+fn attested_header(
+    slot: u64,
+    finalized: &LightClientHeader,
+    next_sync_committee: &SyncCommittee,
+    template: &LightClientHeader,
+) -> (LightClientHeader, Vec<Bytes>, Vec<Bytes>) {
+    // The finalized checkpoint is field 20 of the state and the next sync committee field 23.
+    let checkpoint_index = (FINALIZED_ROOT_GINDEX / 2 - 32) as usize;
+    let next_sync_committee_index = (NEXT_SYNC_COMMITTEE_GINDEX - 32) as usize;
+    let epoch = uint((finalized.beacon.slot.0 / SLOTS_PER_EPOCH) as u128);
+    let mut state = (0..32).map(|i| filler("state", slot * 32 + i)).collect::<Vec<_>>();
+    state[checkpoint_index] = hash(&epoch, &beacon_header_root(&finalized.beacon));
+    state[next_sync_committee_index] = sync_committee_root(next_sync_committee);
+
+    let finality_branch = std::iter::once(epoch)
+        .chain(branch(&state, 32, checkpoint_index))
+        .map(|node| Bytes(node.to_vec()))
+        .collect();
+    let next_sync_committee_branch = branch(&state, 32, next_sync_committee_index)
+        .into_iter()
+        .map(|node| Bytes(node.to_vec()))
+        .collect();
+    (header(slot, merkleize(&state, 32), template), finality_branch, next_sync_committee_branch)
+}
+
+/// A store in `CATCH_UP_START_PERIOD` followed by one sync committee update per period. Each
+/// update is signed by the committee of its period and finalizes a header in that period.
+fn catch_up(real: &ProofInputs) -> ProofInputs {
+    let template = &real.store.finalized_header;
+    let committees = (0..=CATCH_UP_UPDATES)
+        .map(|i| Committee::new(CATCH_UP_START_PERIOD + i))
+        .collect::<Vec<_>>();
+    let period_start = |i: u64| (CATCH_UP_START_PERIOD + i) * SLOTS_PER_PERIOD;
+
+    let store_header = header(period_start(0) + 1024, filler("state", period_start(0)), template);
+    let mut inputs = ProofInputs {
+        sync_committee_updates: Vec::new(),
+        finality_update: real.finality_update.clone(),
+        expected_current_slot: 0,
+        store: LightClientStore {
+            finalized_header: store_header.clone(),
+            current_sync_committee: committees[0].committee.clone(),
+            next_sync_committee: None,
+            optimistic_header: store_header,
+            previous_max_active_participants: 0,
+            current_max_active_participants: 0,
+            best_valid_update: None,
+        },
+        genesis_root: real.genesis_root.clone(),
+        forks: real.forks.clone(),
+    };
+
+    for i in 0..CATCH_UP_UPDATES {
+        let finalized_slot = period_start(i) + 4096;
+        let finalized = header(finalized_slot, filler("state", finalized_slot), template);
+        let next_sync_committee = &committees[i as usize + 1].committee;
+        let (attested, finality_branch, next_sync_committee_branch) =
+            attested_header(finalized_slot + 64, &finalized, next_sync_committee, template);
+        let signature_slot = finalized_slot + 65;
+        let message = signing_root(&attested.beacon, signature_slot, &inputs);
+        inputs.sync_committee_updates.push(Update {
+            attested_header: attested,
+            next_sync_committee: next_sync_committee.clone(),
+            next_sync_committee_branch,
+            finalized_header: finalized,
+            finality_branch,
+            sync_aggregate: committees[i as usize].sign(&message),
+            signature_slot: Quoted(signature_slot),
+        });
+    }
+
+    let last = CATCH_UP_UPDATES - 1;
+    let finalized_slot = period_start(last) + 6144;
+    let finalized = header(finalized_slot, filler("state", finalized_slot), template);
+    let (attested, finality_branch, _) = attested_header(
+        finalized_slot + 64,
+        &finalized,
+        &committees[last as usize + 1].committee,
+        template,
+    );
+    let signature_slot = finalized_slot + 65;
+    let message = signing_root(&attested.beacon, signature_slot, &inputs);
+    inputs.finality_update = FinalityUpdate {
+        attested_header: attested,
+        finalized_header: finalized,
+        finality_branch,
+        sync_aggregate: committees[last as usize].sign(&message),
+        signature_slot: Quoted(signature_slot),
+    };
+    inputs.expected_current_slot = signature_slot + 1;
+    inputs
+}
+
+fn outputs_json(outputs: &Outputs) -> Value {
+    json!({
+        "prev_header": hex_encode(&outputs.prev_header),
+        "prev_head": outputs.prev_head,
+        "header": hex_encode(&outputs.header),
+        "sync_committee_hash": hex_encode(&outputs.sync_committee_hash),
+        "next_sync_committee_hash": hex_encode(&outputs.next_sync_committee_hash),
+        "head": outputs.head,
+    })
+}
+
+fn main() {
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    // The mirrored types must round-trip the real input exactly.
+    let encoded = fs::read(fixtures.join("proof_inputs.cbor")).unwrap();
+    let real: ProofInputs = serde_cbor::from_slice(&encoded).unwrap();
+    assert!(serde_cbor::to_vec(&real).unwrap() == encoded, "proof_inputs.cbor did not round-trip");
+    assert_eq!(real.sync_committee_updates.len(), 1);
+
+    let mut no_updates = real.clone();
+    no_updates.sync_committee_updates.clear();
+    let catch_up = catch_up(&real);
+
+    let mut outputs = Map::new();
+    for (name, inputs) in [
+        ("proof_inputs.cbor", &real),
+        ("proof_inputs_0.cbor", &no_updates),
+        ("proof_inputs_4.cbor", &catch_up),
+    ] {
+        outputs.insert(name.to_string(), outputs_json(&replay(inputs)));
+        if name != "proof_inputs.cbor" {
+            fs::write(fixtures.join(name), serde_cbor::to_vec(inputs).unwrap()).unwrap();
+        }
+    }
+    let outputs = serde_json::to_string_pretty(&Value::Object(outputs)).unwrap();
+    fs::write(fixtures.join("outputs.json"), outputs + "\n").unwrap();
+
+    println!("wrote {}", fixtures.canonicalize().unwrap().display());
+}
@@ -0,0 +1,448 @@
+//! Generates the SSZ witness read by the `ssz-withdrawals` program.
+//!
+//! The witness proves partial withdrawals to an EigenPod from a source beacon block root: the block
+//! header, the validators root and each validator, and for every target slot the historical summary,
+//! the historical block root, its withdrawals root and each withdrawal. The beacon state and blocks
+//! are synthetic, but they are laid out as Capella SSZ containers, so every branch has the depth
+//! and generalized index of a mainnet proof and verifies against the committed block root.
+//!
+//! The witness is bincode-encoded to `fixtures/ssz-withdrawals/witness.bin`. The generator is
+//! deterministic, so rerunning it reproduces the checked-in file:
+//!
+//! ```sh
+//! cargo run --release --manifest-path fixtures/ssz-withdrawals/generator/Cargo.toml
+//! ```
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+type Root = [u8; 32];
+
+/// The slot of the source block whose root anchors every proof.
+const SOURCE_SLOT: u64 = 8225000;
+
+/// The slots whose withdrawals are proven, all at least `SLOTS_PER_HISTORICAL_ROOT` behind the
+/// source slot.
+const TARGET_SLOTS: [u64; 3] = [7855804, 7855805, 7855806];
+
+/// The EigenPod the withdrawals are sent to.
+const EIGENPOD_ADDRESS: [u8; 20] = [
+    0xe9, 0xcd, 0x14, 0x19, 0xa0, 0x15, 0xdd, 0x05, 0xd4, 0x7f, 0x61, 0x39, 0xf5, 0xb8, 0xe8, 0x6b,
+    0x1e, 0x9e, 0x5c, 0xdd,
+];
+
+/// The validators withdrawing to the EigenPod. The last one has exited, so its withdrawal is full.
+const VALIDATOR_INDEXES: [u64; 3] = [795049, 795050, 795051];
+const EXITED_VALIDATOR_INDEX: u64 = 795051;
+
+const CAPELLA_FORK_SLOT: u64 = 6209536;
+const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+const SLOTS_PER_EPOCH: u64 = 32;
+const FAR_FUTURE_EPOCH: u64 = u64::MAX;
+const MAX_WITHDRAWALS_PER_PAYLOAD: u64 = 16;
+const VALIDATOR_COUNT: u64 = 1045984;
+
+/// Tree depths of the SSZ lists, i.e. log2 of their limits.
+const VALIDATOR_REGISTRY_DEPTH: usize = 40;
+const HISTORICAL_ROOTS_DEPTH: usize = 24;
+const WITHDRAWALS_DEPTH: usize = 4;
+
+/// Field indexes of the Capella containers on the proof paths.
+const BLOCK_STATE_ROOT_INDEX: u64 = 3;
+const BLOCK_BODY_ROOT_INDEX: u64 = 4;
+const STATE_VALIDATORS_INDEX: u64 = 11;
+const STATE_HISTORICAL_SUMMARIES_INDEX: u64 = 27;
+const BODY_EXECUTION_PAYLOAD_INDEX: u64 = 9;
+const PAYLOAD_WITHDRAWALS_INDEX: u64 = 14;
+
+// The witness types mirror `programs/ssz-withdrawals/src/beacon/hints.rs`.
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct Witness {
+    block_root: Root,
+    header: HeaderWitness,
+    validators_root: BranchWitness,
+    validators: Vec<ValidatorWitness>,
+    slots: Vec<SlotWitness>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct BranchWitness {
+    leaf: Root,
+    branch: Vec<Root>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct HeaderWitness {
+    slot: u64,
+    proposer_index: u64,
+    parent_root: Root,
+    state_root: Root,
+    body_root: Root,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ValidatorWitness {
+    index: u64,
+    pubkey: Vec<u8>,
+    withdrawal_credentials: Root,
+    effective_balance: u64,
+    slashed: bool,
+    activation_eligibility_epoch: u64,
+    activation_epoch: u64,
+    exit_epoch: u64,
+    withdrawable_epoch: u64,
+    branch: Vec<Root>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct SlotWitness {
+    slot: u64,
+    historical_summary: BranchWitness,
+    block_root: BranchWitness,
+    withdrawals_root: BranchWitness,
+    withdrawals: Vec<WithdrawalWitness>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct WithdrawalWitness {
+    position: u32,
+    index: u64,
+    validator_index: u64,
+    address: [u8; 20],
+    amount: u64,
+    branch: Vec<Root>,
+}
+
+fn hash(left: &Root, right: &Root) -> Root {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// A deterministic stand-in for a field the proofs do not open.
+fn filler(label: &str) -> Root {
+    Sha256::digest(label.as_bytes()).into()
+}
+
+fn uint(value: u64) -> Root {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+fn address_chunk(address: &[u8; 20]) -> Root {
+    let mut chunk = [0u8; 32];
+    chunk[..20].copy_from_slice(address);
+    chunk
+}
+
+/// A sparse binary merkle tree of fixed depth; missing leaves are zero chunks.
+struct Tree {
+    depth: usize,
+    leaves: BTreeMap<u64, Root>,
+    zero_hashes: Vec<Root>,
+}
+
+impl Tree {
+    fn new(depth: usize, leaves: BTreeMap<u64, Root>) -> Self {
+        let mut zero_hashes = vec![[0u8; 32]];
+        for height in 0..depth {
+            zero_hashes.push(hash(&zero_hashes[height], &zero_hashes[height]));
+        }
+        Self { depth, leaves, zero_hashes }
+    }
+
+    /// Merkleizes the fields of a container, padded to the next power of two.
+    fn container(fields: &[Root]) -> Self {
+        let depth = fields.len().next_power_of_two().trailing_zeros() as usize;
+        Self::new(depth, fields.iter().copied().enumerate().map(|(i, f)| (i as u64, f)).collect())
+    }
+
+    fn subtree(&self, height: usize, position: u64) -> Root {
+        let start = position << height;
+        let end = (position + 1) << height;
+        if self.leaves.range(start..end).next().is_none() {
+            return self.zero_hashes[height];
+        }
+        if height == 0 {
+            return self.leaves[&position];
+        }
+        hash(&self.subtree(height - 1, 2 * position), &self.subtree(height - 1, 2 * position + 1))
+    }
+
+    fn root(&self) -> Root {
+        self.subtree(self.depth, 0)
+    }
+
+    /// The sibling nodes from the leaf at `index` up to the root.
+    fn branch(&self, index: u64) -> Vec<Root> {
+        (0..self.depth).map(|height| self.subtree(height, (index >> height) ^ 1)).collect()
+    }
+}
+
+/// An SSZ list: the merkle tree of its elements with the length mixed in.
+struct List {
+    tree: Tree,
+    len: u64,
+}
+
+impl List {
+    fn new(depth: usize, elements: BTreeMap<u64, Root>, len: u64) -> Self {
+        Self { tree: Tree::new(depth, elements), len }
+    }
+
+    fn root(&self) -> Root {
+        hash(&self.tree.root(), &uint(self.len))
+    }
+
+    fn branch(&self, index: u64) -> Vec<Root> {
+        let mut branch = self.tree.branch(index);
+        branch.push(uint(self.len));
+        branch
+    }
+}
+
+fn concat(branches: &[Vec<Root>]) -> Vec<Root> {
+    branches.concat()
+}
+
+fn validator(index: u64) -> (ValidatorWitness, Root) {
+    let seed = Sha256::digest(format!("validator {index} pubkey").as_bytes());
+    let mut pubkey = seed.to_vec();
+    pubkey.extend_from_slice(&Sha256::digest(seed)[..16]);
+
+    let mut withdrawal_credentials = [0u8; 32];
+    withdrawal_credentials[0] = 0x01;
+    withdrawal_credentials[12..].copy_from_slice(&EIGENPOD_ADDRESS);
+
+    let (exit_epoch, withdrawable_epoch) = if index == EXITED_VALIDATOR_INDEX {
+        (245000, 245256)
+    } else {
+        (FAR_FUTURE_EPOCH, FAR_FUTURE_EPOCH)
+    };
+    let witness = ValidatorWitness {
+        index,
+        pubkey,
+        withdrawal_credentials,
+        effective_balance: 32_000_000_000,
+        slashed: false,
+        activation_eligibility_epoch: 210209,
+        activation_epoch: 219962,
+        exit_epoch,
+        withdrawable_epoch,
+        branch: Vec::new(),
+    };
+
+    let mut pubkey_tail = [0u8; 32];
+    pubkey_tail[..16].copy_from_slice(&witness.pubkey[32..]);
+    let pubkey_root = hash(witness.pubkey[..32].try_into().unwrap(), &pubkey_tail);
+    let root = Tree::container(&[
+        pubkey_root,
+        witness.withdrawal_credentials,
+        uint(witness.effective_balance),
+        uint(witness.slashed as u64),
+        uint(witness.activation_eligibility_epoch),
+        uint(witness.activation_epoch),
+        uint(witness.exit_epoch),
+        uint(witness.withdrawable_epoch),
+    ])
+    .root();
+
+    (witness, root)
+}
+
+/// The withdrawals of a target slot: all sixteen go to the EigenPod, and the exited validator's
+/// full withdrawal is the last one of the last slot.
+fn withdrawals(slot: u64) -> Vec<WithdrawalWitness> {
+    let first_index = 26081110 + (slot - TARGET_SLOTS[0]) * MAX_WITHDRAWALS_PER_PAYLOAD;
+    (0..MAX_WITHDRAWALS_PER_PAYLOAD)
+        .map(|position| {
+            let index = first_index + position;
+            let full = slot == TARGET_SLOTS[TARGET_SLOTS.len() - 1]
+                && position == MAX_WITHDRAWALS_PER_PAYLOAD - 1;
+            let (validator_index, amount) = if full {
+                (EXITED_VALIDATOR_INDEX, 32_000_000_000)
+            } else {
+                (VALIDATOR_INDEXES[(position % 2) as usize], 17_000_000 + (index % 1000) * 1000)
+            };
+            WithdrawalWitness {
+                position: position as u32,
+                index,
+                validator_index,
+                address: EIGENPOD_ADDRESS,
+                amount,
+                branch: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+fn withdrawal_root(withdrawal: &WithdrawalWitness) -> Root {
+    Tree::container(&[
+        uint(withdrawal.index),
+        uint(withdrawal.validator_index),
+        address_chunk(&withdrawal.address),
+        uint(withdrawal.amount),
+    ])
+    .root()
+}
+
+/// A historical block at a target slot, with its withdrawals proven up to the block root.
+struct HistoricalBlock {
+    root: Root,
+    withdrawals_root: BranchWitness,
+    withdrawals: Vec<WithdrawalWitness>,
+}
+
+fn historical_block(slot: u64) -> HistoricalBlock {
+    let mut withdrawals = withdrawals(slot);
+    let withdrawals_list = List::new(
+        WITHDRAWALS_DEPTH,
+        withdrawals.iter().map(|w| (w.position as u64, withdrawal_root(w))).collect(),
+        withdrawals.len() as u64,
+    );
+    for withdrawal in &mut withdrawals {
+        withdrawal.branch = withdrawals_list.branch(withdrawal.position as u64);
+    }
+
+    let mut payload_fields: Vec<Root> =
+        (0..15).map(|i| filler(&format!("slot {slot} payload field {i}"))).collect();
+    payload_fields[PAYLOAD_WITHDRAWALS_INDEX as usize] = withdrawals_list.root();
+    let payload = Tree::container(&payload_fields);
+
+    let mut body_fields: Vec<Root> =
+        (0..11).map(|i| filler(&format!("slot {slot} body field {i}"))).collect();
+    body_fields[BODY_EXECUTION_PAYLOAD_INDEX as usize] = payload.root();
+    let body = Tree::container(&body_fields);
+
+    let block = Tree::container(&[
+        uint(slot),
+        uint(slot % 1_000_000),
+        filler(&format!("slot {slot} parent root")),
+        filler(&format!("slot {slot} state root")),
+        body.root(),
+    ]);
+
+    HistoricalBlock {
+        root: block.root(),
+        withdrawals_root: BranchWitness {
+            leaf: withdrawals_list.root(),
+            branch: concat(&[
+                payload.branch(PAYLOAD_WITHDRAWALS_INDEX),
+                body.branch(BODY_EXECUTION_PAYLOAD_INDEX),
+                block.branch(BLOCK_BODY_ROOT_INDEX),
+            ]),
+        },
+        withdrawals,
+    }
+}
+
+fn main() {
+    let mut validators = Vec::new();
+    let mut validator_leaves = BTreeMap::new();
+    for index in VALIDATOR_INDEXES {
+        let (validator, root) = validator(index);
+        validators.push(validator);
+        validator_leaves.insert(index, root);
+    }
+    let validators_list = List::new(VALIDATOR_REGISTRY_DEPTH, validator_leaves, VALIDATOR_COUNT);
+    for validator in &mut validators {
+        validator.branch = validators_list.branch(validator.index);
+    }
+
+    // All target slots fall in the same historical summary period.
+    let period = (TARGET_SLOTS[0] - CAPELLA_FORK_SLOT) / SLOTS_PER_HISTORICAL_ROOT;
+    let blocks: Vec<HistoricalBlock> = TARGET_SLOTS.iter().map(|&s| historical_block(s)).collect();
+    let mut block_roots: BTreeMap<u64, Root> = (0..SLOTS_PER_HISTORICAL_ROOT)
+        .map(|i| (i, filler(&format!("period {period} block root {i}"))))
+        .collect();
+    for (slot, block) in TARGET_SLOTS.iter().zip(&blocks) {
+        assert_eq!((slot - CAPELLA_FORK_SLOT) / SLOTS_PER_HISTORICAL_ROOT, period);
+        block_roots.insert(slot % SLOTS_PER_HISTORICAL_ROOT, block.root);
+    }
+    let block_roots = Tree::new(SLOTS_PER_HISTORICAL_ROOT.trailing_zeros() as usize, block_roots);
+    let summary =
+        Tree::container(&[block_roots.root(), filler(&format!("period {period} state root"))]);
+
+    let summary_count = (SOURCE_SLOT - CAPELLA_FORK_SLOT) / SLOTS_PER_HISTORICAL_ROOT;
+    let mut summaries: BTreeMap<u64, Root> =
+        (0..summary_count).map(|i| (i, filler(&format!("historical summary {i}")))).collect();
+    summaries.insert(period, summary.root());
+    let summaries_list = List::new(HISTORICAL_ROOTS_DEPTH, summaries, summary_count);
+
+    let mut state_fields: Vec<Root> = (0..28).map(|i| filler(&format!("state field {i}"))).collect();
+    state_fields[STATE_VALIDATORS_INDEX as usize] = validators_list.root();
+    state_fields[STATE_HISTORICAL_SUMMARIES_INDEX as usize] = summaries_list.root();
+    let state = Tree::container(&state_fields);
+
+    let header = HeaderWitness {
+        slot: SOURCE_SLOT,
+        proposer_index: 980811,
+        parent_root: filler("source parent root"),
+        state_root: state.root(),
+        body_root: filler("source body root"),
+    };
+    let header_tree = Tree::container(&[
+        uint(header.slot),
+        uint(header.proposer_index),
+        header.parent_root,
+        header.state_root,
+        header.body_root,
+    ]);
+    let header_branch = header_tree.branch(BLOCK_STATE_ROOT_INDEX);
+
+    let slots = TARGET_SLOTS
+        .iter()
+        .zip(blocks)
+        .map(|(&slot, block)| SlotWitness {
+            slot,
+            historical_summary: BranchWitness {
+                leaf: summary.root(),
+                branch: concat(&[
+                    summaries_list.branch(period),
+                    state.branch(STATE_HISTORICAL_SUMMARIES_INDEX),
+                    header_branch.clone(),
+                ]),
+            },
+            block_root: BranchWitness {
+                leaf: block.root,
+                branch: concat(&[block_roots.branch(slot % SLOTS_PER_HISTORICAL_ROOT), summary.branch(0)]),
+            },
+            withdrawals_root: block.withdrawals_root,
+            withdrawals: block.withdrawals,
+        })
+        .collect::<Vec<_>>();
+
+    let witness = Witness {
+        block_root: header_tree.root(),
+        header,
+        validators_root: BranchWitness {
+            leaf: validators_list.root(),
+            branch: concat(&[state.branch(STATE_VALIDATORS_INDEX), header_branch]),
+        },
+        validators,
+        slots,
+    };
+
+    let partial_sum: u64 = witness
+        .slots
+        .iter()
+        .flat_map(|s| s.withdrawals.iter().map(move |w| (s.slot, w)))
+        .filter(|(slot, w)| {
+            let validator = witness.validators.iter().find(|v| v.index == w.validator_index).unwrap();
+            slot / SLOTS_PER_EPOCH < validator.withdrawable_epoch
+        })
+        .map(|(_, w)| w.amount)
+        .sum();
+
+    let out = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../witness.bin");
+    fs::write(&out, bincode::serialize(&witness).unwrap()).unwrap();
+
+    println!("block root: 0x{}", witness.block_root.iter().map(|b| format!("{b:02x}")).collect::<String>());
+    println!("partial withdrawals sum: {partial_sum}");
+    println!("wrote {}", out.display());
+}
@@ -0,0 +1,391 @@
+//! Generates the `skipping-1` and `skipping-2` light blocks used by `tendermint-skipping`.
+//!
+//! The two blocks are 100,000 heights apart. The trusted block is signed by 150 validators. The
+//! untrusted block is signed by a set that keeps 100 of them and adds 50 new ones, so the light
+//! client has to take the skipping path: more than 1/3 of the trusted power signed the target
+//! commit, and more than 2/3 of the target set signed it. Keys are derived from fixed seeds.
+//!
+//! The blocks are written in the same CometBFT RPC JSON format as the Celestia fixtures next to
+//! them. Before writing, the generator recomputes the header hash, validator set hash and a vote
+//! signature of `fixtures/tendermint/1` to check its encoding against real chain data. Rerunning
+//! it reproduces the checked-in files:
+//!
+//! ```sh
+//! cargo run --release --manifest-path fixtures/tendermint/generator/Cargo.toml
+//! ```
+
+use std::{fs, path::PathBuf};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+const CHAIN_ID: &str = "skipping-testnet";
+const TRUSTED_HEIGHT: u64 = 10_000;
+const UNTRUSTED_HEIGHT: u64 = 110_000;
+const BLOCK_TIME_SECS: i64 = 6;
+const TRUSTED_TIME: &str = "2024-01-01T00:00:00.123456789Z";
+
+/// The trusted set is validators `0..150`; the untrusted set is validators `50..200`.
+const TRUSTED_VALIDATORS: std::ops::Range<u64> = 0..150;
+const UNTRUSTED_VALIDATORS: std::ops::Range<u64> = 50..200;
+
+/// Precommit, in `SignedMsgType`.
+const PRECOMMIT: u64 = 2;
+/// `BlockIDFlagCommit`.
+const BLOCK_ID_FLAG_COMMIT: u64 = 2;
+
+// Protobuf encoding, enough of it to hash headers and validators and to build vote sign bytes.
+
+fn varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn field_varint(field: u64, value: u64, out: &mut Vec<u8>) {
+    if value != 0 {
+        varint(field << 3, out);
+        varint(value, out);
+    }
+}
+
+fn field_fixed64(field: u64, value: i64, out: &mut Vec<u8>) {
+    if value != 0 {
+        varint((field << 3) | 1, out);
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn field_bytes(field: u64, value: &[u8], out: &mut Vec<u8>) {
+    if !value.is_empty() {
+        field_message(field, value, out);
+    }
+}
+
+/// A length-delimited field that is written even when empty, as for non-nullable messages.
+fn field_message(field: u64, value: &[u8], out: &mut Vec<u8>) {
+    varint((field << 3) | 2, out);
+    varint(value.len() as u64, out);
+    out.extend_from_slice(value);
+}
+
+fn timestamp(time: &DateTime<Utc>) -> Vec<u8> {
+    let mut out = Vec::new();
+    field_varint(1, time.timestamp() as u64, &mut out);
+    field_varint(2, time.timestamp_subsec_nanos() as u64, &mut out);
+    out
+}
+
+fn part_set_header(total: u64, hash: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    field_varint(1, total, &mut out);
+    field_bytes(2, hash, &mut out);
+    out
+}
+
+fn block_id(hash: &[u8], parts_total: u64, parts_hash: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    field_bytes(1, hash, &mut out);
+    field_message(2, &part_set_header(parts_total, parts_hash), &mut out);
+    out
+}
+
+/// `cdcEncode` of a string, integer or bytes header field: the matching protobuf wrapper type.
+fn wrapped_bytes(value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    field_bytes(1, value, &mut out);
+    out
+}
+
+fn wrapped_u64(value: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    field_varint(1, value, &mut out);
+    out
+}
+
+// RFC 6962 merkle tree, as used for header and validator set hashes.
+
+fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+fn merkle_root(items: &[Vec<u8>]) -> [u8; 32] {
+    match items.len() {
+        0 => sha256(&[]),
+        1 => sha256(&[&[0], &items[0]]),
+        n => {
+            let split = n.next_power_of_two() / 2;
+            let left = merkle_root(&items[..split]);
+            let right = merkle_root(&items[split..]);
+            sha256(&[&[1], &left, &right])
+        }
+    }
+}
+
+// JSON accessors for the RPC format.
+
+fn hex_field(value: &Value) -> Vec<u8> {
+    hex::decode(value.as_str().unwrap()).unwrap()
+}
+
+fn u64_field(value: &Value) -> u64 {
+    match value {
+        Value::String(s) => s.parse().unwrap(),
+        v => v.as_u64().unwrap(),
+    }
+}
+
+fn time_field(value: &Value) -> DateTime<Utc> {
+    value.as_str().unwrap().parse().unwrap()
+}
+
+fn format_time(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Nanos, true)
+}
+
+fn header_hash(header: &Value) -> [u8; 32] {
+    let mut version = Vec::new();
+    field_varint(1, u64_field(&header["version"]["block"]), &mut version);
+    field_varint(2, u64_field(&header["version"]["app"]), &mut version);
+
+    let last_block_id = &header["last_block_id"];
+    let fields = vec![
+        version,
+        wrapped_bytes(header["chain_id"].as_str().unwrap().as_bytes()),
+        wrapped_u64(u64_field(&header["height"])),
+        timestamp(&time_field(&header["time"])),
+        block_id(
+            &hex_field(&last_block_id["hash"]),
+            u64_field(&last_block_id["parts"]["total"]),
+            &hex_field(&last_block_id["parts"]["hash"]),
+        ),
+        wrapped_bytes(&hex_field(&header["last_commit_hash"])),
+        wrapped_bytes(&hex_field(&header["data_hash"])),
+        wrapped_bytes(&hex_field(&header["validators_hash"])),
+        wrapped_bytes(&hex_field(&header["next_validators_hash"])),
+        wrapped_bytes(&hex_field(&header["consensus_hash"])),
+        wrapped_bytes(&hex_field(&header["app_hash"])),
+        wrapped_bytes(&hex_field(&header["last_results_hash"])),
+        wrapped_bytes(&hex_field(&header["evidence_hash"])),
+        wrapped_bytes(&hex_field(&header["proposer_address"])),
+    ];
+    merkle_root(&fields)
+}
+
+fn validator_set_hash(validators: &[Value]) -> [u8; 32] {
+    let leaves: Vec<Vec<u8>> = validators
+        .iter()
+        .map(|validator| {
+            let pubkey = BASE64.decode(validator["pub_key"]["value"].as_str().unwrap()).unwrap();
+            let mut public_key = Vec::new();
+            field_bytes(1, &pubkey, &mut public_key);
+            let mut out = Vec::new();
+            field_message(1, &public_key, &mut out);
+            field_varint(2, u64_field(&validator["voting_power"]), &mut out);
+            out
+        })
+        .collect();
+    merkle_root(&leaves)
+}
+
+/// The length-prefixed `CanonicalVote` a validator signs for a commit signature.
+fn vote_sign_bytes(chain_id: &str, commit: &Value, time: &DateTime<Utc>) -> Vec<u8> {
+    let block = &commit["block_id"];
+    let mut canonical_block_id = Vec::new();
+    field_bytes(1, &hex_field(&block["hash"]), &mut canonical_block_id);
+    field_message(
+        2,
+        &part_set_header(u64_field(&block["parts"]["total"]), &hex_field(&block["parts"]["hash"])),
+        &mut canonical_block_id,
+    );
+
+    let mut vote = Vec::new();
+    field_varint(1, PRECOMMIT, &mut vote);
+    field_fixed64(2, u64_field(&commit["height"]) as i64, &mut vote);
+    field_fixed64(3, u64_field(&commit["round"]) as i64, &mut vote);
+    field_message(4, &canonical_block_id, &mut vote);
+    field_message(5, &timestamp(time), &mut vote);
+    field_bytes(6, chain_id.as_bytes(), &mut vote);
+
+    let mut out = Vec::new();
+    varint(vote.len() as u64, &mut out);
+    out.extend_from_slice(&vote);
+    out
+}
+
+fn read_fixture(dir: &PathBuf, name: &str) -> Value {
+    serde_json::from_slice(&fs::read(dir.join(name)).unwrap()).unwrap()
+}
+
+/// Checks the encodings above against the real Celestia light block in `fixtures/tendermint/1`.
+fn check_against_celestia(fixtures: &PathBuf) {
+    let dir = fixtures.join("1");
+    let signed_header = &read_fixture(&dir, "signed_header.json")["result"]["signed_header"];
+    let validators = read_fixture(&dir, "validators.json");
+    let validators = validators["result"]["validators"].as_array().unwrap();
+    let header = &signed_header["header"];
+    let commit = &signed_header["commit"];
+
+    assert_eq!(header_hash(header).to_vec(), hex_field(&commit["block_id"]["hash"]));
+    assert_eq!(validator_set_hash(validators).to_vec(), hex_field(&header["validators_hash"]));
+
+    let signature = &commit["signatures"][0];
+    let validator = validators
+        .iter()
+        .find(|v| v["address"] == signature["validator_address"])
+        .unwrap();
+    let pubkey = BASE64.decode(validator["pub_key"]["value"].as_str().unwrap()).unwrap();
+    let pubkey = VerifyingKey::from_bytes(&pubkey.try_into().unwrap()).unwrap();
+    let sig = BASE64.decode(signature["signature"].as_str().unwrap()).unwrap();
+    let sig = ed25519_dalek::Signature::from_slice(&sig).unwrap();
+    let sign_bytes = vote_sign_bytes(
+        header["chain_id"].as_str().unwrap(),
+        commit,
+        &time_field(&signature["timestamp"]),
+    );
+    pubkey.verify(&sign_bytes, &sig).expect("celestia vote signature does not verify");
+}
+
+struct Validator {
+    key: SigningKey,
+    address: Vec<u8>,
+    voting_power: u64,
+}
+
+fn validator(index: u64) -> Validator {
+    let key = SigningKey::from_bytes(&sha256(&[format!("tendermint validator {index}").as_bytes()]));
+    let address = sha256(&[key.verifying_key().as_bytes()])[..20].to_vec();
+    Validator { key, address, voting_power: 10_000_000 - index * 20_000 }
+}
+
+/// A validator set in CometBFT order: by voting power, descending, then by address.
+fn validator_set(indexes: std::ops::Range<u64>) -> Vec<Validator> {
+    let mut validators: Vec<Validator> = indexes.map(validator).collect();
+    validators.sort_by(|a, b| b.voting_power.cmp(&a.voting_power).then(a.address.cmp(&b.address)));
+    validators
+}
+
+fn validators_json(validators: &[Validator], height: u64) -> Value {
+    let infos: Vec<Value> = validators
+        .iter()
+        .map(|v| {
+            json!({
+                "address": hex::encode_upper(&v.address),
+                "pub_key": {
+                    "type": "tendermint/PubKeyEd25519",
+                    "value": BASE64.encode(v.key.verifying_key().as_bytes()),
+                },
+                "voting_power": v.voting_power.to_string(),
+                "proposer_priority": "0",
+            })
+        })
+        .collect();
+    json!({
+        "jsonrpc": "2.0",
+        "id": -1,
+        "result": {
+            "block_height": height.to_string(),
+            "validators": infos,
+            "count": validators.len().to_string(),
+            "total": validators.len().to_string(),
+        }
+    })
+}
+
+fn filler(label: &str) -> String {
+    hex::encode_upper(sha256(&[label.as_bytes()]))
+}
+
+fn signed_header_json(height: u64, time: DateTime<Utc>, validators: &[Validator]) -> Value {
+    let validators_hash = hex::encode_upper(validator_set_hash(
+        validators_json(validators, height)["result"]["validators"].as_array().unwrap(),
+    ));
+    let header = json!({
+        "version": { "block": "11", "app": "1" },
+        "chain_id": CHAIN_ID,
+        "height": height.to_string(),
+        "time": format_time(&time),
+        "last_block_id": {
+            "hash": filler(&format!("{height} last block")),
+            "parts": { "total": 1, "hash": filler(&format!("{height} last block parts")) },
+        },
+        "last_commit_hash": filler(&format!("{height} last commit")),
+        "data_hash": filler(&format!("{height} data")),
+        "validators_hash": validators_hash,
+        "next_validators_hash": validators_hash,
+        "consensus_hash": filler("consensus params"),
+        "app_hash": filler(&format!("{height} app")),
+        "last_results_hash": filler(&format!("{height} last results")),
+        "evidence_hash": hex::encode_upper(sha256(&[])),
+        "proposer_address": hex::encode_upper(&validators[0].address),
+    });
+
+    let mut commit = json!({
+        "height": height.to_string(),
+        "round": 0,
+        "block_id": {
+            "hash": hex::encode_upper(header_hash(&header)),
+            "parts": { "total": 1, "hash": filler(&format!("{height} parts")) },
+        },
+        "signatures": [],
+    });
+    let signatures: Vec<Value> = validators
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let vote_time = time + Duration::milliseconds(1000 + i as i64);
+            let signature = v.key.sign(&vote_sign_bytes(CHAIN_ID, &commit, &vote_time));
+            json!({
+                "block_id_flag": BLOCK_ID_FLAG_COMMIT,
+                "validator_address": hex::encode_upper(&v.address),
+                "timestamp": format_time(&vote_time),
+                "signature": BASE64.encode(signature.to_bytes()),
+            })
+        })
+        .collect();
+    commit["signatures"] = Value::Array(signatures);
+
+    json!({
+        "jsonrpc": "2.0",
+        "id": -1,
+        "result": {
+            "signed_header": { "header": header, "commit": commit },
+            "canonical": true,
+        }
+    })
+}
+
+fn write_light_block(dir: PathBuf, height: u64, time: DateTime<Utc>, validators: &[Validator]) {
+    fs::create_dir_all(&dir).unwrap();
+    let write = |name: &str, value: &Value| {
+        fs::write(dir.join(name), serde_json::to_string_pretty(value).unwrap() + "\n").unwrap();
+    };
+    write("signed_header.json", &signed_header_json(height, time, validators));
+    write("validators.json", &validators_json(validators, height));
+    write("next_validators.json", &validators_json(validators, height + 1));
+}
+
+fn main() {
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    check_against_celestia(&fixtures);
+
+    let trusted_time: DateTime<Utc> = TRUSTED_TIME.parse().unwrap();
+    let untrusted_time = trusted_time
+        + Duration::seconds((UNTRUSTED_HEIGHT - TRUSTED_HEIGHT) as i64 * BLOCK_TIME_SECS);
+
+    let trusted = validator_set(TRUSTED_VALIDATORS);
+    let untrusted = validator_set(UNTRUSTED_VALIDATORS);
+    write_light_block(fixtures.join("skipping-1"), TRUSTED_HEIGHT, trusted_time, &trusted);
+    write_light_block(fixtures.join("skipping-2"), UNTRUSTED_HEIGHT, untrusted_time, &untrusted);
+
+    println!("wrote {} and {}", fixtures.join("skipping-1").display(), fixtures.join("skipping-2").display());
+}
@@ -0,0 +1,35 @@
+#![no_main]
+
+use sha2::{Digest, Sha256};
+
+guest_io::entrypoint!(main);
+
+/// Verifies every inner proof handed to the guest as a deferred proof (SP1) or an assumption
+/// (RISC Zero), and commits the keys of the inner programs and a digest of their outputs.
+pub fn main() {
+    // The vkeys (SP1) or image IDs (RISC Zero) of the inner programs, and their public outputs.
+    let keys: Vec<[u32; 8]> = guest_io::read();
+    let outputs: Vec<Vec<u8>> = guest_io::read();
+    assert_eq!(keys.len(), outputs.len(), "every inner proof needs a key and an output");
+
+    // The outputs are committed as the SHA-256 of the SHA-256 of each output, in order.
+    let mut outputs_hasher = Sha256::new();
+    for (key, output) in keys.iter().zip(outputs.iter()) {
+        verify(key, output);
+        outputs_hasher.update(Sha256::digest(output));
+    }
+    let outputs_digest: [u8; 32] = outputs_hasher.finalize().into();
+
+    guest_io::commit(&(keys, outputs_digest));
+}
+
+#[cfg(feature = "sp1")]
+fn verify(vkey: &[u32; 8], public_values: &[u8]) {
+    let public_values_digest = Sha256::digest(public_values);
+    sp1_zkvm::lib::verify::verify_sp1_proof(vkey, &public_values_digest.into());
+}
+
+#[cfg(feature = "risc0")]
+fn verify(image_id: &[u32; 8], journal: &[u8]) {
+    risc0_zkvm::guest::env::verify(*image_id, journal).unwrap();
+}
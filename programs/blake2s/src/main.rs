@@ -0,0 +1,21 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+use blake2::{Blake2s256, Digest};
+
+fn main() {
+    let message_size: u32 = guest_io::read();
+    let data: Vec<u8> = guest_io::read();
+
+    // Hash each message separately and fold the digests together, so that every hash is used.
+    let mut digest = [0u8; 32];
+    for message in data.chunks(message_size as usize) {
+        for (byte, hash_byte) in digest.iter_mut().zip(Blake2s256::digest(message)) {
+            *byte ^= hash_byte;
+        }
+    }
+
+    // Commit the number of bytes hashed, so the evaluator can check it against its input.
+    guest_io::commit(&(data.len() as u64, digest));
+}
@@ -0,0 +1,50 @@
+#![no_main]
+guest_io::entrypoint!(main);
+
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    multi_miller_loop, G1Affine, G2Affine, G2Prepared, G2Projective, Gt,
+};
+use serde::Deserialize;
+use sha2::Sha256;
+
+/// The domain separation tag of Ethereum's BLS signatures.
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The signatures to verify, as written by the evaluator.
+#[derive(Deserialize)]
+struct SignatureBatch {
+    keys: Vec<Vec<u8>>,
+    messages: Vec<(u32, Vec<u8>)>,
+    signatures: Vec<Vec<u8>>,
+}
+
+fn main() {
+    let batch: SignatureBatch = guest_io::read();
+
+    let keys = batch
+        .keys
+        .iter()
+        .map(|key| G1Affine::from_compressed(key.as_slice().try_into().unwrap()).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(batch.signatures.len(), 1, "the batch has more than one aggregate signature");
+    let signature =
+        G2Affine::from_compressed(batch.signatures[0].as_slice().try_into().unwrap()).unwrap();
+
+    // e(-g1, signature) * prod e(key_i, H(message_i)) == 1
+    let mut terms = vec![(-G1Affine::generator(), G2Prepared::from(signature))];
+    for (i, message) in &batch.messages {
+        let point =
+            <G2Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(message, DST);
+        terms.push((keys[*i as usize], G2Prepared::from(G2Affine::from(point))));
+    }
+    let terms = terms.iter().map(|(key, point)| (key, point)).collect::<Vec<_>>();
+
+    assert_eq!(
+        multi_miller_loop(&terms).final_exponentiation(),
+        Gt::identity(),
+        "BLS aggregate signature verification failed"
+    );
+
+    guest_io::commit(&(batch.messages.len() as u32));
+}
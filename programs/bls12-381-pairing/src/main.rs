@@ -0,0 +1,32 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+#[cfg(all(feature = "sp1", not(feature = "baseline")))]
+use bls12_381_sp1 as bls12_381;
+
+use bls12_381::{pairing, G1Affine, G2Affine, Scalar};
+
+fn main() {
+    // Pairs of 64 random bytes, reduced to the scalars of each check.
+    let scalars: Vec<(Vec<u8>, Vec<u8>)> = guest_io::read();
+
+    // e(a * g1, b * g2) == e(g1, g2) * (a * b), in the additive notation of `bls12_381`.
+    let base = pairing(&G1Affine::generator(), &G2Affine::generator());
+    for (a, b) in &scalars {
+        assert!(a.len() == 64 && b.len() == 64, "each scalar must be 64 bytes");
+        let a = Scalar::from_bytes_wide(a.as_slice().try_into().unwrap());
+        let b = Scalar::from_bytes_wide(b.as_slice().try_into().unwrap());
+        assert_eq!(
+            pairing(
+                &G1Affine::from(G1Affine::generator() * a),
+                &G2Affine::from(G2Affine::generator() * b)
+            ),
+            base * (a * b),
+            "the BLS12-381 pairing is not bilinear"
+        );
+    }
+
+    // Commit the number of checks, each of which computes one pairing besides the shared base.
+    guest_io::commit(&(scalars.len() as u32));
+}
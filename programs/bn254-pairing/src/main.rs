@@ -0,0 +1,28 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+#[cfg(all(feature = "sp1", not(feature = "baseline")))]
+use bn_sp1 as bn;
+
+use bn::{pairing, Fr, Group, G1, G2};
+
+fn main() {
+    // Pairs of 64 random bytes, reduced to the scalars of each check.
+    let scalars: Vec<(Vec<u8>, Vec<u8>)> = guest_io::read();
+
+    // e(a * g1, b * g2) == e(g1, g2)^(a * b)
+    let base = pairing(G1::one(), G2::one());
+    for (a, b) in &scalars {
+        assert!(a.len() == 64 && b.len() == 64, "each scalar must be 64 bytes");
+        let a = Fr::interpret(a.as_slice().try_into().unwrap());
+        let b = Fr::interpret(b.as_slice().try_into().unwrap());
+        assert!(
+            pairing(G1::one() * a, G2::one() * b) == base.pow(a * b),
+            "the BN254 pairing is not bilinear"
+        );
+    }
+
+    // Commit the number of checks, each of which computes one pairing besides the shared base.
+    guest_io::commit(&(scalars.len() as u32));
+}
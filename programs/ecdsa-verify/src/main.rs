@@ -0,0 +1,54 @@
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde::Deserialize;
+
+guest_io::entrypoint!(main);
+
+/// The signatures to verify, as written by the evaluator.
+#[derive(Deserialize)]
+struct SignatureBatch {
+    keys: Vec<Vec<u8>>,
+    messages: Vec<(u32, Vec<u8>)>,
+    signatures: Vec<Vec<u8>>,
+}
+
+fn main() {
+    // Decode the verifying keys, messages, and signatures from the inputs.
+    let batch: SignatureBatch = guest_io::read();
+
+    let keys = batch
+        .keys
+        .iter()
+        .map(|key| VerifyingKey::from_sec1_bytes(key).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        batch.messages.len(),
+        batch.signatures.len(),
+        "the batch has a different number of messages and signatures"
+    );
+
+    // Verify each signature, panicking if verification fails.
+    for ((i, message), signature) in batch.messages.iter().zip(&batch.signatures) {
+        let signature = Signature::from_slice(signature).unwrap();
+        keys[*i as usize].verify(message, &signature).expect("ECDSA signature verification failed");
+    }
+
+    // Commit the number of signatures that were verified.
+    guest_io::commit(&(batch.messages.len() as u32));
+}
@@ -2,11 +2,7 @@
 
 use std::hint::black_box;
 
-#[cfg(feature = "risc0")]
-risc0_zkvm::guest::entry!(main);
-
-#[cfg(feature = "sp1")]
-sp1_zkvm::entrypoint!(main);
+guest_io::entrypoint!(main);
 
 fn fibonacci(n: u32) -> u32 {
     let mut a = 0;
@@ -20,10 +16,7 @@ fn fibonacci(n: u32) -> u32 {
 }
 
 pub fn main() {
-    #[cfg(feature = "risc0")]
-    let n: u32 = risc0_zkvm::guest::env::read();
-    #[cfg(feature = "sp1")]
-    let n: u32 = sp1_zkvm::io::read();
+    let n: u32 = guest_io::read();
     let result = black_box(fibonacci(black_box(n)));
     println!("result: {}", result);
 }
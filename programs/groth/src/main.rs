@@ -0,0 +1,15 @@
+#![no_main]
+guest_io::entrypoint!(main);
+
+// SP1 verifies the proof with its patched `substrate-bn`, and RISC Zero with `risc0-groth16`.
+#[cfg(feature = "risc0")]
+mod risc0;
+#[cfg(feature = "sp1")]
+mod sp1;
+
+fn main() {
+    #[cfg(feature = "sp1")]
+    sp1::verify();
+    #[cfg(feature = "risc0")]
+    risc0::verify();
+}
@@ -0,0 +1,10 @@
+use risc0_groth16::{Fr, Seal, Verifier, VerifyingKey};
+use risc0_zkvm::sha::Digestible;
+
+pub fn verify() {
+    let (seal, public_inputs, verifying_key): (Seal, Vec<Fr>, VerifyingKey) = guest_io::read();
+
+    Verifier::new(&seal, &public_inputs, &verifying_key).unwrap().verify().unwrap();
+
+    guest_io::commit(&(verifying_key.digest(), public_inputs.digest()));
+}
@@ -0,0 +1,55 @@
+use bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Gt, G1, G2};
+use serde::{Deserialize, Serialize};
+
+type G1Bytes = [[u8; 32]; 2];
+type G2Bytes = [[[u8; 32]; 2]; 2];
+
+/// Groth16 proof, verifying key and public inputs as 32 byte big-endian field elements.
+#[derive(Serialize, Deserialize)]
+struct Groth16FixtureBytes {
+    a: G1Bytes,
+    b: G2Bytes,
+    c: G1Bytes,
+    alpha: G1Bytes,
+    beta: G2Bytes,
+    gamma: G2Bytes,
+    delta: G2Bytes,
+    ic: Vec<G1Bytes>,
+    public_inputs: Vec<[u8; 32]>,
+}
+
+fn fq(bytes: &[u8; 32]) -> Fq {
+    Fq::from_slice(bytes).unwrap()
+}
+
+fn g1(point: &G1Bytes) -> G1 {
+    AffineG1::new(fq(&point[0]), fq(&point[1])).unwrap().into()
+}
+
+fn g2(point: &G2Bytes) -> G2 {
+    let x = Fq2::new(fq(&point[0][0]), fq(&point[0][1]));
+    let y = Fq2::new(fq(&point[1][0]), fq(&point[1][1]));
+    AffineG2::new(x, y).unwrap().into()
+}
+
+pub fn verify() {
+    let fixture: Groth16FixtureBytes = guest_io::read();
+    assert_eq!(fixture.ic.len(), fixture.public_inputs.len() + 1);
+
+    // Accumulate the public inputs into the verifying key.
+    let mut vk_x = g1(&fixture.ic[0]);
+    for (input, ic) in fixture.public_inputs.iter().zip(&fixture.ic[1..]) {
+        vk_x = vk_x + g1(ic) * Fr::from_slice(input).unwrap();
+    }
+
+    // Check e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1.
+    let pairing = pairing_batch(&[
+        (-g1(&fixture.a), g2(&fixture.b)),
+        (g1(&fixture.alpha), g2(&fixture.beta)),
+        (vk_x, g2(&fixture.gamma)),
+        (g1(&fixture.c), g2(&fixture.delta)),
+    ]);
+    assert!(pairing == Gt::one(), "invalid groth16 proof");
+
+    guest_io::commit(&fixture.public_inputs);
+}
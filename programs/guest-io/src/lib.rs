@@ -0,0 +1,81 @@
+//! The guest I/O of the zkVM selected by the `sp1` or `risc0` feature, so that each guest is written
+//! once for both zkVMs.
+
+#[cfg(all(feature = "sp1", feature = "risc0"))]
+compile_error!("enable only one of the `sp1` and `risc0` features");
+#[cfg(not(any(feature = "sp1", feature = "risc0")))]
+compile_error!("enable one of the `sp1` and `risc0` features");
+
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "risc0")]
+#[doc(hidden)]
+pub use risc0_zkvm;
+#[cfg(feature = "sp1")]
+#[doc(hidden)]
+pub use sp1_zkvm;
+
+/// Declares the entrypoint of the guest.
+#[cfg(feature = "sp1")]
+#[macro_export]
+macro_rules! entrypoint {
+    ($path:path) => {
+        $crate::sp1_zkvm::entrypoint!($path);
+    };
+}
+
+/// Declares the entrypoint of the guest.
+#[cfg(feature = "risc0")]
+#[macro_export]
+macro_rules! entrypoint {
+    ($path:path) => {
+        $crate::risc0_zkvm::guest::entry!($path);
+    };
+}
+
+/// Reads the next value the evaluator wrote.
+pub fn read<T: DeserializeOwned>() -> T {
+    #[cfg(feature = "sp1")]
+    return sp1_zkvm::io::read();
+    #[cfg(feature = "risc0")]
+    return risc0_zkvm::guest::env::read();
+}
+
+/// Reads the next bytes the evaluator wrote, with `SP1Stdin::write_vec` for SP1 and as a `Vec<u8>`
+/// for RISC Zero.
+pub fn read_vec() -> Vec<u8> {
+    #[cfg(feature = "sp1")]
+    return sp1_zkvm::io::read_vec();
+    #[cfg(feature = "risc0")]
+    return risc0_zkvm::guest::env::read();
+}
+
+/// Commits a value to the public values of SP1 or the journal of RISC Zero.
+pub fn commit<T: Serialize>(value: &T) {
+    #[cfg(feature = "sp1")]
+    sp1_zkvm::io::commit(value);
+    #[cfg(feature = "risc0")]
+    risc0_zkvm::guest::env::commit(value);
+}
+
+/// Runs `f` and reports the number of cycles it took under `label`.
+///
+/// SP1 has no cycle counter in the guest, so this wraps `f` in a cycle tracker that the SP1
+/// executor reports. RISC Zero reads its cycle counter before and after `f` and prints the
+/// difference.
+pub fn cycle_count<T>(label: &str, f: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "sp1")]
+    {
+        println!("cycle-tracker-report-start: {}", label);
+        let result = f();
+        println!("cycle-tracker-report-end: {}", label);
+        result
+    }
+    #[cfg(feature = "risc0")]
+    {
+        let start = risc0_zkvm::guest::env::cycle_count();
+        let result = f();
+        println!("{}: {} cycles", label, risc0_zkvm::guest::env::cycle_count() - start);
+        result
+    }
+}
@@ -1,83 +0,0 @@
-#![no_main]
-sp1_zkvm::entrypoint!(main);
-
-use helios_consensus_core::consensus_spec::MainnetConsensusSpec;
-use helios_consensus_core::types::Forks;
-use helios_consensus_core::types::{FinalityUpdate, LightClientStore, Update};
-
-use alloy_primitives::B256;
-use helios_consensus_core::{
-    apply_finality_update, apply_update, verify_finality_update, verify_update,
-};
-use ssz_rs::prelude::*;
-use tree_hash::TreeHash;
-
-#[derive(serde::Deserialize, Debug)]
-pub struct ProofInputs {
-    pub sync_committee_updates: Vec<Update<MainnetConsensusSpec>>,
-    pub finality_update: FinalityUpdate<MainnetConsensusSpec>,
-    pub expected_current_slot: u64,
-    pub store: LightClientStore<MainnetConsensusSpec>,
-    pub genesis_root: B256,
-    pub forks: Forks,
-}
-
-fn main() {
-    let encoded_inputs = sp1_zkvm::io::read_vec();
-
-    let ProofInputs {
-        sync_committee_updates,
-        finality_update,
-        expected_current_slot,
-        mut store,
-        genesis_root,
-        forks,
-    } = serde_cbor::from_slice(&encoded_inputs).unwrap();
-
-    let _prev_header: B256 = store.finalized_header.beacon().tree_hash_root();
-    let _prev_head = store.finalized_header.beacon().slot;
-
-    // 1. Apply sync committee updates, if any
-    for (index, update) in sync_committee_updates.iter().enumerate() {
-        println!(
-            "Processing update {} of {}.",
-            index + 1,
-            sync_committee_updates.len()
-        );
-        let update_is_valid =
-            verify_update(update, expected_current_slot, &store, genesis_root, &forks).is_ok();
-
-        if !update_is_valid {
-            panic!("Update {} is invalid!", index + 1);
-        }
-        println!("Update {} is valid.", index + 1);
-        apply_update(&mut store, update);
-    }
-
-    // 2. Apply finality update
-    let finality_update_is_valid = verify_finality_update(
-        &finality_update,
-        expected_current_slot,
-        &store,
-        genesis_root,
-        &forks,
-    )
-    .is_ok();
-    if !finality_update_is_valid {
-        panic!("Finality update is invalid!");
-    }
-    println!("Finality update is valid.");
-
-    apply_finality_update(&mut store, &finality_update);
-
-    // 3. Commit new state root, header, and sync committee for usage in the on-chain contract
-    let _header: B256 = store.finalized_header.beacon().tree_hash_root();
-    let _sync_committee_hash: B256 = store.current_sync_committee.tree_hash_root();
-    let _next_sync_committee_hash: B256 = match &mut store.next_sync_committee {
-        Some(next_sync_committee) => next_sync_committee.tree_hash_root(),
-        None => B256::ZERO,
-    };
-    let _head = store.finalized_header.beacon().slot;
-
-    // Commit here
-}
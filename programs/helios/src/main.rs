@@ -1,6 +1,5 @@
-
 #![no_main]
-risc0_zkvm::guest::entry!(main);
+guest_io::entrypoint!(main);
 
 use helios_consensus_core::consensus_spec::MainnetConsensusSpec;
 use helios_consensus_core::types::Forks;
@@ -23,8 +22,19 @@ pub struct ProofInputs {
     pub forks: Forks,
 }
 
+/// The verified state, committed as public values.
+#[derive(serde::Serialize, Debug)]
+pub struct ProofOutputs {
+    pub prev_header: [u8; 32],
+    pub prev_head: u64,
+    pub header: [u8; 32],
+    pub sync_committee_hash: [u8; 32],
+    pub next_sync_committee_hash: [u8; 32],
+    pub head: u64,
+}
+
 fn main() {
-    let encoded_inputs: Vec<u8> = risc0_zkvm::guest::env::read();
+    let encoded_inputs = guest_io::read_vec();
 
     let ProofInputs {
         sync_committee_updates,
@@ -35,16 +45,12 @@ fn main() {
         forks,
     } = serde_cbor::from_slice(&encoded_inputs).unwrap();
 
-    let _prev_header: B256 = store.finalized_header.beacon().tree_hash_root();
-    let _prev_head = store.finalized_header.beacon().slot;
+    let prev_header: B256 = store.finalized_header.beacon().tree_hash_root();
+    let prev_head = store.finalized_header.beacon().slot;
 
     // 1. Apply sync committee updates, if any
     for (index, update) in sync_committee_updates.iter().enumerate() {
-        println!(
-            "Processing update {} of {}.",
-            index + 1,
-            sync_committee_updates.len()
-        );
+        println!("Processing update {} of {}.", index + 1, sync_committee_updates.len());
         let update_is_valid =
             verify_update(update, expected_current_slot, &store, genesis_root, &forks).is_ok();
 
@@ -72,13 +78,21 @@ fn main() {
     apply_finality_update(&mut store, &finality_update);
 
     // 3. Commit new state root, header, and sync committee for usage in the on-chain contract
-    let _header: B256 = store.finalized_header.beacon().tree_hash_root();
-    let _sync_committee_hash: B256 = store.current_sync_committee.tree_hash_root();
-    let _next_sync_committee_hash: B256 = match &mut store.next_sync_committee {
+    let header: B256 = store.finalized_header.beacon().tree_hash_root();
+    let sync_committee_hash: B256 = store.current_sync_committee.tree_hash_root();
+    let next_sync_committee_hash: B256 = match &mut store.next_sync_committee {
         Some(next_sync_committee) => next_sync_committee.tree_hash_root(),
         None => B256::ZERO,
     };
-    let _head = store.finalized_header.beacon().slot;
+    let head = store.finalized_header.beacon().slot;
 
-    // Commit here
+    let outputs = ProofOutputs {
+        prev_header: prev_header.0,
+        prev_head,
+        header: header.0,
+        sync_committee_hash: sync_committee_hash.0,
+        next_sync_committee_hash: next_sync_committee_hash.0,
+        head,
+    };
+    guest_io::commit(&outputs);
 }
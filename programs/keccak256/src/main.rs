@@ -18,15 +18,25 @@
 
 use sha3::{Digest as _, Keccak256};
 
-sp1_zkvm::entrypoint!(main);
+guest_io::entrypoint!(main);
 
 fn main() {
-    let data: Vec<u8> = sp1_zkvm::io::read();
-    let hash = keccak(&data);
-    sp1_zkvm::io::commit(&hash)
+    let message_size: u32 = guest_io::read();
+    let data: Vec<u8> = guest_io::read();
+
+    // Hash each message separately and fold the digests together, so that every hash is used.
+    let mut digest = [0u8; 32];
+    for message in data.chunks(message_size as usize) {
+        for (byte, hash_byte) in digest.iter_mut().zip(keccak(message)) {
+            *byte ^= hash_byte;
+        }
+    }
+
+    // Commit the number of bytes hashed, so the evaluator can check it against its input.
+    guest_io::commit(&(data.len() as u64, digest))
 }
 
 #[inline]
 pub fn keccak(data: impl AsRef<[u8]>) -> [u8; 32] {
     Keccak256::digest(data).into()
-}
\ No newline at end of file
+}
@@ -16,20 +16,13 @@
 
 #![no_main]
 
-#[cfg(feature = "risc0")]
-risc0_zkvm::guest::entry!(main);
-
-#[cfg(feature = "sp1")]
-sp1_zkvm::entrypoint!(main);
+guest_io::entrypoint!(main);
 
 #[cfg(target_os = "zkvm")]
 use core::arch::asm;
 
 fn main() {
-    #[cfg(feature = "sp1")]
-    let iterations: usize = sp1_zkvm::io::read();
-    #[cfg(feature = "risc0")]
-    let iterations: usize = risc0_zkvm::guest::env::read();
+    let iterations: usize = guest_io::read();
 
     for i in 0..iterations {
         memory_barrier(&i);
@@ -44,4 +37,4 @@ pub fn memory_barrier<T>(ptr: *const T) {
     }
     #[cfg(not(target_os = "zkvm"))]
     core::sync::atomic::fence(core::sync::atomic::Ordering::SeqCst)
-}
\ No newline at end of file
+}
@@ -0,0 +1,106 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+use std::{collections::BTreeMap, hint::black_box};
+
+use serde::Deserialize;
+
+/// The largest array the random-access workload indexes into, in words (16 MiB).
+const MAX_ARRAY_WORDS: usize = 1 << 22;
+
+/// The largest buffer the memcpy workload copies at once, in words (1 MiB).
+const MAX_BUFFER_WORDS: usize = 1 << 18;
+
+/// The memory access pattern to run, in the order of `MemoryWorkload` in the evaluator.
+#[derive(Deserialize)]
+enum Workload {
+    RandomAccess,
+    Memcpy,
+    Sort,
+    Btreemap,
+}
+
+fn main() {
+    let workload: Workload = guest_io::read();
+    let ops: u32 = guest_io::read();
+    let seed: u64 = guest_io::read();
+
+    let mut rng = XorShift::new(seed);
+    let ops = ops as usize;
+    let checksum = match workload {
+        Workload::RandomAccess => random_access(ops, &mut rng),
+        Workload::Memcpy => memcpy(ops),
+        Workload::Sort => sort(ops, &mut rng),
+        Workload::Btreemap => btreemap(ops, &mut rng),
+    };
+
+    guest_io::commit(&checksum);
+}
+
+/// Reads and overwrites `ops` random words of an array. The array grows with `ops` up to 16 MiB,
+/// so that zeroing it never dominates the accesses.
+fn random_access(ops: usize, rng: &mut XorShift) -> u64 {
+    let len = ops.next_power_of_two().min(MAX_ARRAY_WORDS);
+    let mut array = vec![0u32; len];
+    let mut checksum = 0u64;
+    for i in 0..ops {
+        let index = rng.next() as usize & (len - 1);
+        checksum = checksum.wrapping_add(array[index] as u64);
+        array[index] = i as u32;
+    }
+    checksum
+}
+
+/// Copies `words` words back and forth between two buffers of at most 1 MiB each.
+fn memcpy(words: usize) -> u64 {
+    let len = words.clamp(1, MAX_BUFFER_WORDS);
+    let mut src: Vec<u32> = (0..len as u32).collect();
+    let mut dst = vec![0u32; len];
+    let mut remaining = words;
+    while remaining > 0 {
+        let n = remaining.min(len);
+        dst[..n].copy_from_slice(&src[..n]);
+        std::mem::swap(&mut src, &mut dst);
+        // Keep the compiler from eliding copies whose result is overwritten by the next one.
+        black_box(&mut src);
+        remaining -= n;
+    }
+    src.iter().fold(0, |checksum, &word| checksum.wrapping_add(word as u64))
+}
+
+/// Sorts `len` random words.
+fn sort(len: usize, rng: &mut XorShift) -> u64 {
+    let mut values: Vec<u32> = (0..len).map(|_| rng.next() as u32).collect();
+    values.sort_unstable();
+    values
+        .iter()
+        .enumerate()
+        .fold(0, |checksum, (i, &value)| checksum.wrapping_add(i as u64 * value as u64))
+}
+
+/// Inserts `ops` random keys into a `BTreeMap`.
+fn btreemap(ops: usize, rng: &mut XorShift) -> u64 {
+    let mut map = BTreeMap::new();
+    for i in 0..ops {
+        map.insert(rng.next() as u32, i as u32);
+    }
+    map.len() as u64
+}
+
+/// A xorshift64 generator, cheap enough not to hide the memory accesses it drives.
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        // The state must never be zero.
+        Self(seed.wrapping_add(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
@@ -0,0 +1,40 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+#[cfg(all(feature = "risc0", not(feature = "baseline")))]
+use rsa_risc0 as rsa;
+#[cfg(all(feature = "sp1", not(feature = "baseline")))]
+use rsa_sp1 as rsa;
+
+use rsa::{hazmat::rsa_encrypt, BigUint, RsaPublicKey};
+use serde::Deserialize;
+
+/// The public exponent of RSA keys, which the zkVMs' patches accelerate.
+const EXPONENT: u32 = 65537;
+
+/// The modulus and base as big-endian bytes, as written by the evaluator.
+#[derive(Deserialize)]
+struct ModexpInput {
+    count: u32,
+    modulus: Vec<u8>,
+    base: Vec<u8>,
+}
+
+fn main() {
+    let input: ModexpInput = guest_io::read();
+    assert_eq!(input.modulus.last().map(|byte| byte & 1), Some(1), "the modulus is empty or even");
+    assert_eq!(input.base.len(), input.modulus.len(), "the base is not as wide as the modulus");
+
+    // Raise the base to the public exponent `count` times, as in the RSA public key operation.
+    let key = RsaPublicKey::new_unchecked(
+        BigUint::from_bytes_be(&input.modulus),
+        BigUint::from(EXPONENT),
+    );
+    let mut power = BigUint::from_bytes_be(&input.base);
+    for _ in 0..input.count {
+        power = rsa_encrypt(&key, &power).unwrap();
+    }
+
+    guest_io::commit(&power.to_bytes_be());
+}
@@ -0,0 +1,73 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+#[cfg(all(feature = "risc0", not(feature = "baseline")))]
+use risc0_zkvm_platform::syscall::sys_bigint;
+#[cfg(all(feature = "sp1", not(feature = "baseline")))]
+use sp1_zkvm::lib::sys_bigint;
+
+use serde::Deserialize;
+
+/// The `sys_bigint` operation of modular multiplication, the only one either zkVM implements.
+#[cfg(not(feature = "baseline"))]
+const OP_MULTIPLY: u32 = 0;
+
+/// The operands as little-endian words, as written by the evaluator.
+#[derive(Deserialize)]
+struct Mulmod256Input {
+    count: u32,
+    a: [u32; 8],
+    b: [u32; 8],
+    modulus: [u32; 8],
+}
+
+fn main() {
+    let input: Mulmod256Input = guest_io::read();
+    assert_eq!(input.modulus[0] & 1, 1, "the modulus is even");
+
+    let product = mulmod_chain(input.count, input.a, &input.b, &input.modulus);
+
+    guest_io::commit(&product);
+}
+
+/// Multiplies `a` by `b` modulo `modulus` `count` times with the zkVM's 256-bit modular
+/// multiplication.
+#[cfg(not(feature = "baseline"))]
+fn mulmod_chain(count: u32, a: [u32; 8], b: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let mut product = a;
+    for _ in 0..count {
+        let mut result = [0u32; 8];
+        unsafe { sys_bigint(&mut result, OP_MULTIPLY, &product, b, modulus) };
+        product = result;
+    }
+    product
+}
+
+/// Multiplies `a` by `b` modulo `modulus` `count` times in Montgomery form, in pure Rust.
+#[cfg(feature = "baseline")]
+fn mulmod_chain(count: u32, a: [u32; 8], b: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+
+    let params = DynResidueParams::new(&to_uint(modulus));
+    let b = DynResidue::new(&to_uint(b), params);
+    let mut product = DynResidue::new(&to_uint(&a), params);
+    for _ in 0..count {
+        product = product.mul(&b);
+    }
+    from_uint(&product.retrieve())
+}
+
+#[cfg(feature = "baseline")]
+fn to_uint(words: &[u32; 8]) -> crypto_bigint::U256 {
+    let bytes = words.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+    crypto_bigint::U256::from_le_slice(&bytes)
+}
+
+#[cfg(feature = "baseline")]
+fn from_uint(uint: &crypto_bigint::U256) -> [u32; 8] {
+    use crypto_bigint::Encoding;
+
+    let bytes = uint.to_le_bytes();
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
@@ -0,0 +1,36 @@
+#![no_main]
+guest_io::entrypoint!(main);
+
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde::Deserialize;
+
+/// The signatures to verify, as written by the evaluator.
+#[derive(Deserialize)]
+struct SignatureBatch {
+    keys: Vec<Vec<u8>>,
+    messages: Vec<(u32, Vec<u8>)>,
+    signatures: Vec<Vec<u8>>,
+}
+
+fn main() {
+    let batch: SignatureBatch = guest_io::read();
+
+    let keys = batch
+        .keys
+        .iter()
+        .map(|key| VerifyingKey::from_sec1_bytes(key).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        batch.messages.len(),
+        batch.signatures.len(),
+        "the batch has a different number of messages and signatures"
+    );
+
+    for ((i, message), signature) in batch.messages.iter().zip(&batch.signatures) {
+        let signature = Signature::from_slice(signature).unwrap();
+        keys[*i as usize].verify(message, &signature).expect("P-256 signature verification failed");
+    }
+
+    guest_io::commit(&(batch.messages.len() as u32));
+}
@@ -0,0 +1,57 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+use zkhash::{
+    ark_ff::{BigInteger, One, PrimeField, Zero},
+    fields::babybear::FpBabyBear,
+    poseidon2::{poseidon2::Poseidon2, poseidon2_instance_babybear::POSEIDON2_BABYBEAR_16_PARAMS},
+};
+
+/// The number of elements absorbed per permutation of the width-16 sponge.
+const RATE: usize = 8;
+/// The number of bytes packed into each BabyBear element.
+const BYTES_PER_ELEMENT: usize = 3;
+
+fn main() {
+    let message_size: u32 = guest_io::read();
+    let data: Vec<u8> = guest_io::read();
+
+    let poseidon2 = Poseidon2::new(&POSEIDON2_BABYBEAR_16_PARAMS);
+
+    // Hash each message separately and fold the digests together, so that every hash is used.
+    let mut digest = [0u8; 32];
+    for message in data.chunks(message_size as usize) {
+        for (byte, hash_byte) in digest.iter_mut().zip(hash(&poseidon2, message)) {
+            *byte ^= hash_byte;
+        }
+    }
+
+    // Commit the number of bytes hashed, so the evaluator can check it against its input.
+    guest_io::commit(&(data.len() as u64, digest));
+}
+
+/// Hashes `message` with a Poseidon2 sponge, packing its bytes into field elements and padding it
+/// with a single one element.
+fn hash(poseidon2: &Poseidon2<FpBabyBear>, message: &[u8]) -> [u8; 32] {
+    let mut elements = message
+        .chunks(BYTES_PER_ELEMENT)
+        .map(FpBabyBear::from_le_bytes_mod_order)
+        .collect::<Vec<_>>();
+    elements.push(FpBabyBear::one());
+
+    let mut state = vec![FpBabyBear::zero(); poseidon2.get_t()];
+    for block in elements.chunks(RATE) {
+        for (element, input) in state.iter_mut().zip(block) {
+            *element += input;
+        }
+        state = poseidon2.permutation(&state);
+    }
+
+    // The digest is the first 8 elements of the state.
+    let mut digest = [0u8; 32];
+    for (bytes, element) in digest.chunks_mut(4).zip(&state) {
+        bytes.copy_from_slice(&element.into_bigint().to_bytes_le()[..4]);
+    }
+    digest
+}
@@ -0,0 +1,51 @@
+#![no_main]
+
+guest_io::entrypoint!(main);
+
+use zkhash::{
+    ark_ff::{BigInteger, One, PrimeField, Zero},
+    fields::bn256::FpBN256,
+    poseidon2::{poseidon2::Poseidon2, poseidon2_instance_bn256::POSEIDON2_BN256_PARAMS},
+};
+
+/// The number of elements absorbed per permutation of the width-3 sponge.
+const RATE: usize = 2;
+/// The number of bytes packed into each BN254 element.
+const BYTES_PER_ELEMENT: usize = 31;
+
+fn main() {
+    let message_size: u32 = guest_io::read();
+    let data: Vec<u8> = guest_io::read();
+
+    let poseidon2 = Poseidon2::new(&POSEIDON2_BN256_PARAMS);
+
+    // Hash each message separately and fold the digests together, so that every hash is used.
+    let mut digest = [0u8; 32];
+    for message in data.chunks(message_size as usize) {
+        for (byte, hash_byte) in digest.iter_mut().zip(hash(&poseidon2, message)) {
+            *byte ^= hash_byte;
+        }
+    }
+
+    // Commit the number of bytes hashed, so the evaluator can check it against its input.
+    guest_io::commit(&(data.len() as u64, digest));
+}
+
+/// Hashes `message` with a Poseidon2 sponge, packing its bytes into field elements and padding it
+/// with a single one element.
+fn hash(poseidon2: &Poseidon2<FpBN256>, message: &[u8]) -> [u8; 32] {
+    let mut elements =
+        message.chunks(BYTES_PER_ELEMENT).map(FpBN256::from_le_bytes_mod_order).collect::<Vec<_>>();
+    elements.push(FpBN256::one());
+
+    let mut state = vec![FpBN256::zero(); poseidon2.get_t()];
+    for block in elements.chunks(RATE) {
+        for (element, input) in state.iter_mut().zip(block) {
+            *element += input;
+        }
+        state = poseidon2.permutation(&state);
+    }
+
+    // The digest is the first element of the state.
+    state[0].into_bigint().to_bytes_le().try_into().unwrap()
+}
@@ -2,11 +2,11 @@
 
 use rsp_client_executor::{io::ClientExecutorInput, ClientExecutor, EthereumVariant};
 
-sp1_zkvm::entrypoint!(main);
+guest_io::entrypoint!(main);
 
 pub fn main() {
     // Read the input.
-    let input: Vec<u8> = sp1_zkvm::io::read_vec();
+    let input: Vec<u8> = guest_io::read_vec();
     let input = bincode::deserialize::<ClientExecutorInput>(&input).unwrap();
 
     // Execute the block.
@@ -15,4 +15,4 @@ pub fn main() {
     let block_hash = header.hash_slow();
 
     println!("block_hash: {:?}", block_hash);
-}
\ No newline at end of file
+}
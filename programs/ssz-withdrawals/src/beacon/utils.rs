@@ -2,7 +2,9 @@ use alloy_primitives::U256;
 use ssz_rs::prelude::Node;
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "sp1")] {
+    if #[cfg(feature = "baseline")] {
+        use sha2::{Digest, Sha256};
+    } else if #[cfg(feature = "sp1")] {
         use sha2_sp1::{Digest, Sha256};
     } else if #[cfg(feature = "risc0")] {
         use sha2_risc0::{Digest, Sha256};
@@ -41,9 +43,7 @@ pub fn is_valid_merkle_big_branch<'a>(
 }
 
 pub fn branch_from_bytes(s: &[[u8; 32]]) -> Vec<Node> {
-    s.iter()
-        .map(|hex| node_from_bytes(*hex))
-        .collect::<Vec<Node>>()
+    s.iter().map(|hex| node_from_bytes(*hex)).collect::<Vec<Node>>()
 }
 
 pub fn node_from_bytes(s: [u8; 32]) -> Node {
@@ -3,7 +3,9 @@ use ssz_rs::prelude::Node;
 use std::ops::Div;
 
 cfg_if::cfg_if! {
-    if #[cfg(feature = "sp1")] {
+    if #[cfg(feature = "baseline")] {
+        use sha2::{Digest, Sha256};
+    } else if #[cfg(feature = "sp1")] {
         use sha2_sp1::{Digest, Sha256};
     } else if #[cfg(feature = "risc0")] {
         use sha2_risc0::{Digest, Sha256};
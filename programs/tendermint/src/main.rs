@@ -0,0 +1,118 @@
+#![no_main]
+
+use core::time::Duration;
+use serde::Deserialize;
+use tendermint::{node::Id, validator::Info};
+use tendermint_light_client_verifier::{
+    options::Options,
+    types::{LightBlock, SignedHeader, TrustThreshold, ValidatorSet},
+    ProdVerifier, Verdict, Verifier,
+};
+use tendermint_proto::{
+    v0_37::types::{SignedHeader as RawSignedHeader, ValidatorSet as RawValidatorSet},
+    Protobuf,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CommitResponse {
+    pub result: SignedHeaderWrapper,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignedHeaderWrapper {
+    pub signed_header: SignedHeader,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValidatorSetResponse {
+    pub result: BlockValidatorSet,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BlockValidatorSet {
+    pub block_height: String,
+    pub validators: Vec<Info>,
+    pub count: String,
+    pub total: String,
+}
+
+/// Light client options and how the light blocks are encoded.
+#[derive(Debug, Deserialize)]
+pub struct VerifierInput {
+    pub trust_threshold: (u64, u64),
+    pub trusting_period_secs: u64,
+    pub clock_drift_secs: u64,
+    pub peer_id: [u8; 20],
+    /// Whether the light blocks are CometBFT RPC JSON rather than protobuf.
+    pub json: bool,
+}
+
+/// The parts of a light block, each encoded as given by [`VerifierInput::json`].
+#[derive(Debug, Deserialize)]
+pub struct EncodedLightBlock {
+    pub signed_header: Vec<u8>,
+    pub validators: Vec<u8>,
+    pub next_validators: Vec<u8>,
+}
+
+fn decode_light_block(input: &VerifierInput, block: EncodedLightBlock) -> LightBlock {
+    let (signed_header, validators, next_validators) = if input.json {
+        let commit_response: CommitResponse =
+            serde_json::from_slice(&block.signed_header).expect("Failed to parse JSON");
+        let validators_response: ValidatorSetResponse =
+            serde_json::from_slice(&block.validators).expect("Failed to parse JSON");
+        let next_validators_response: ValidatorSetResponse =
+            serde_json::from_slice(&block.next_validators).expect("Failed to parse JSON");
+        (
+            commit_response.result.signed_header,
+            ValidatorSet::new(validators_response.result.validators, None),
+            ValidatorSet::new(next_validators_response.result.validators, None),
+        )
+    } else {
+        (
+            <SignedHeader as Protobuf<RawSignedHeader>>::decode_vec(&block.signed_header)
+                .expect("Failed to decode signed header"),
+            <ValidatorSet as Protobuf<RawValidatorSet>>::decode_vec(&block.validators)
+                .expect("Failed to decode validators"),
+            <ValidatorSet as Protobuf<RawValidatorSet>>::decode_vec(&block.next_validators)
+                .expect("Failed to decode next validators"),
+        )
+    };
+
+    LightBlock::new(signed_header, validators, next_validators, Id::new(input.peer_id))
+}
+
+guest_io::entrypoint!(main);
+
+pub fn main() {
+    let input: VerifierInput = guest_io::read();
+    let trusted_block = guest_io::read::<EncodedLightBlock>();
+    let untrusted_block = guest_io::read::<EncodedLightBlock>();
+
+    let light_block_1 = decode_light_block(&input, trusted_block);
+    let light_block_2 = decode_light_block(&input, untrusted_block);
+
+    let vp = ProdVerifier::default();
+    let (numerator, denominator) = input.trust_threshold;
+    let opt = Options {
+        trust_threshold: TrustThreshold::new(numerator, denominator).unwrap(),
+        trusting_period: Duration::from_secs(input.trusting_period_secs),
+        clock_drift: Duration::from_secs(input.clock_drift_secs),
+    };
+
+    let verify_time = light_block_2.time() + Duration::from_secs(20);
+
+    let verdict = vp.verify_update_header(
+        light_block_2.as_untrusted_state(),
+        light_block_1.as_trusted_state(),
+        &opt,
+        verify_time.unwrap(),
+    );
+
+    match verdict {
+        Verdict::Success => {
+            println!("success");
+        }
+        v => panic!("expected success, got: {:?}", v),
+    }
+}
@@ -1,26 +1,25 @@
 #![no_main]
 
-#[cfg(feature = "sp1")]
-sp1_zkvm::entrypoint!(main);
-
-#[cfg(feature = "risc0")]
-risc0_zkvm::guest::entry!(main);
+guest_io::entrypoint!(main);
 
 use std::str::FromStr;
 
 use base64::prelude::*;
 
-#[cfg(feature = "risc0")]
+#[cfg(any(feature = "risc0", feature = "baseline"))]
 use rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
-#[cfg(feature = "sp1")]
+#[cfg(all(feature = "sp1", not(feature = "baseline")))]
 use sp1_rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "risc0")]
+#[cfg(feature = "baseline")]
+use sha2::{Digest, Sha256};
+
+#[cfg(all(feature = "risc0", not(feature = "baseline")))]
 use sha2_risc0::{Digest, Sha256};
 
-#[cfg(feature = "sp1")]
+#[cfg(all(feature = "sp1", not(feature = "baseline")))]
 use sha2_sp1::{Digest, Sha256};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -33,26 +32,13 @@ struct EmailInputs {
 }
 
 pub fn main() {
-    #[cfg(feature = "sp1")]
-    let email_inputs: EmailInputs = sp1_zkvm::io::read();
-
-    #[cfg(feature = "risc0")]
-    let email_inputs: EmailInputs = risc0_zkvm::guest::env::read();
+    let email_inputs: EmailInputs = guest_io::read();
 
     let signature_verified = verify_signature(&email_inputs);
     let body_verified = verify_body(&email_inputs);
-    
-    #[cfg(feature = "sp1")]
-    {
-        sp1_zkvm::io::commit(&signature_verified);
-        sp1_zkvm::io::commit(&body_verified);
-    }
-
-    #[cfg(feature = "risc0")]
-    {
-        risc0_zkvm::guest::env::commit(&signature_verified);
-        risc0_zkvm::guest::env::commit(&body_verified);
-    }
+
+    guest_io::commit(&signature_verified);
+    guest_io::commit(&body_verified);
 }
 
 fn verify_body(email_inputs: &EmailInputs) -> bool {
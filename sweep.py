@@ -1,22 +1,119 @@
 import argparse
+import csv
+import os
 import subprocess
+from collections import defaultdict
 from itertools import product
 
+# The programs that take the hash input options.
+HASH_PROGRAMS = ("sha256", "keccak256", "blake2s", "blake3", "ripemd160", "poseidon2")
+
 
 def run_benchmark(
-    filename, trials, programs, provers, hashfns, shard_sizes, block_1, block_2
+    filename,
+    trials,
+    programs,
+    provers,
+    hashfns,
+    shard_sizes,
+    block_1,
+    block_2,
+    risc0_receipt_kinds,
+    risc0_max_segment_po2s,
+    risc0_accelerators,
+    hash_message_sizes,
+    hash_bytes_list,
+    seed,
+    precompiles,
+    guest_opt_levels,
+    guest_ltos,
+    guest_codegen_units_list,
+    guest_panics,
+    threads_list,
+    timeout,
+    max_rss,
 ):
-    option_combinations = product(provers, programs, hashfns, shard_sizes)
-    for prover, program, hashfn, shard_size in option_combinations:
-        first_shard_size = shard_sizes[0]
-        if (
-            prover != "sp1" and shard_size != first_shard_size
-        ):  # Only sp1 supports different shard sizes
+    # The core_khz of each successful run at each thread count, by the rest of its options.
+    scaling = defaultdict(lambda: defaultdict(list))
+    option_combinations = product(
+        provers,
+        programs,
+        hashfns,
+        shard_sizes,
+        risc0_receipt_kinds,
+        risc0_max_segment_po2s,
+        risc0_accelerators,
+        hash_message_sizes,
+        hash_bytes_list,
+        guest_opt_levels,
+        guest_ltos,
+        guest_codegen_units_list,
+        guest_panics,
+        threads_list,
+    )
+    for options in option_combinations:
+        (
+            prover,
+            program,
+            hashfn,
+            shard_size,
+            receipt_kind,
+            max_segment_po2,
+            accelerators,
+            hash_message_size,
+            hash_bytes,
+            guest_opt_level,
+            guest_lto,
+            guest_codegen_units,
+            guest_panic,
+            threads,
+        ) = options
+        if prover != "risc0" and (
+            receipt_kind != risc0_receipt_kinds[0]
+            or max_segment_po2 != risc0_max_segment_po2s[0]
+            or accelerators != risc0_accelerators[0]
+        ):  # The RISC Zero options only apply to risc0
             continue
-        print(f"Running: {program}, {prover}, {hashfn}, {shard_size}")
+        if prover != "risc0" and hashfn != hashfns[0]:
+            continue  # Only risc0 supports different hash functions
+        if not program.startswith(HASH_PROGRAMS) and (
+            hash_message_size != hash_message_sizes[0] or hash_bytes != hash_bytes_list[0]
+        ):  # The hash input options only apply to the hashing programs
+            continue
+
+        env = os.environ.copy()
+        env["SEED"] = str(seed)
+        # The baseline programs always prove the unpatched build.
+        env["PRECOMPILES"] = "off" if program.endswith("-baseline") else precompiles
+        if timeout is not None:
+            env["TIMEOUT"] = str(timeout)
+        if max_rss is not None:
+            env["MAX_RSS"] = str(max_rss)
+        if hash_message_size is not None:
+            env["HASH_MESSAGE_SIZE"] = str(hash_message_size)
+        env["HASH_BYTES"] = hash_bytes
+        env["GUEST_OPT_LEVEL"] = guest_opt_level
+        if guest_lto is not None:
+            env["GUEST_LTO"] = guest_lto
+        env["GUEST_CODEGEN_UNITS"] = str(guest_codegen_units)
+        env["GUEST_PANIC"] = guest_panic
+        if threads is not None:
+            env["THREADS"] = str(threads)
+        if prover == "risc0":
+            env["RISC0_RECEIPT_KIND"] = receipt_kind
+            if max_segment_po2 is not None:
+                env["RISC0_MAX_SEGMENT_PO2"] = str(max_segment_po2)
+            env["RISC0_FEATURE_bigint2"] = "1" if "bigint2" in accelerators else "0"
+            env["RISC0_FEATURE_keccak"] = "1" if "keccak" in accelerators else "0"
+            print(
+                f"Running: {program}, {prover}, {hashfn}, {shard_size}, {receipt_kind}, "
+                f"{max_segment_po2}, {accelerators}"
+            )
+        else:
+            print(f"Running: {program}, {prover}, {hashfn}, {shard_size}")
         for _ in range(trials):
             if program == "reth1":
-                subprocess.run(
+                result = subprocess.run(
                     [
                         "bash",
                         "eval.sh",
@@ -26,10 +123,11 @@ def run_benchmark(
                         str(shard_size),
                         filename,
                         block_1,
-                    ]
+                    ],
+                    env=env,
                 )
             elif program == "reth2":
-                subprocess.run(
+                result = subprocess.run(
                     [
                         "bash",
                         "eval.sh",
@@ -39,23 +137,11 @@ def run_benchmark(
                         str(shard_size),
                         filename,
                         block_2,
-                    ]
+                    ],
+                    env=env,
                 )
             else:
-                if "groth16" in program:
-                    subprocess.run(
-                        [
-                            "bash",
-                            "eval.sh",
-                            "fibonacci20k",
-                            prover,
-                            hashfn,
-                            str(shard_size),
-                            filename,
-                            "buildOnly",
-                        ]
-                    )
-                subprocess.run(
+                result = subprocess.run(
                     [
                         "bash",
                         "eval.sh",
@@ -64,8 +150,76 @@ def run_benchmark(
                         hashfn,
                         str(shard_size),
                         filename,
-                    ]
+                    ],
+                    env=env,
                 )
+            if result.returncode == 0 and len(threads_list) > 1:
+                scaling[options[:-1]][threads].append(latest_core_khz())
+
+    if scaling:
+        report_scaling(filename, scaling)
+
+
+def latest_core_khz():
+    """Returns the core_khz of the last row the eval binary wrote."""
+    with open("benchmarks/benchmarks_latest.csv") as f:
+        rows = list(csv.DictReader(f))
+    return float(rows[-1]["core_khz"])
+
+
+def report_scaling(filename, scaling):
+    """Prints and writes the speedup and efficiency of each thread count over the lowest one."""
+    path = f"benchmarks/{filename}_scaling.csv"
+    with open(path, "w", newline="") as f:
+        writer = csv.writer(f)
+        writer.writerow(["program", "prover", "threads", "core_khz", "speedup", "efficiency"])
+        for options, runs in scaling.items():
+            program, prover = options[1], options[0]
+            base_threads = min(runs)
+            base_khz = sum(runs[base_threads]) / len(runs[base_threads])
+            print(f"Scaling of {program} on {prover}:")
+            for threads in sorted(runs):
+                khz = sum(runs[threads]) / len(runs[threads])
+                speedup = khz / base_khz
+                efficiency = speedup * base_threads / threads
+                print(f"  {threads:>4} threads: {khz:.2f} kHz, {speedup:.2f}x, {efficiency:.0%} efficient")
+                writer.writerow([program, prover, threads, khz, speedup, efficiency])
+    print(f"Wrote the scaling results to {path}")
+
+
+def thread_count(value):
+    """Parses an entry of `--threads`: a positive thread count or `scale`."""
+    if value == "scale":
+        return value
+    try:
+        count = int(value)
+    except ValueError:
+        count = 0
+    if count < 1:
+        raise argparse.ArgumentTypeError(
+            f"invalid thread count {value!r}, expected a positive number or 'scale'"
+        )
+    return count
+
+
+def thread_counts(threads):
+    """Expands each `scale` into 1, 2, 4, ... up to the number of hardware threads, keeping the
+    order of the counts and dropping repeated ones."""
+    if threads == [None]:
+        return threads
+    scale = []
+    count = 1
+    while count < os.cpu_count():
+        scale.append(count)
+        count *= 2
+    scale.append(os.cpu_count())
+    counts = []
+    for entry in threads:
+        for count in scale if entry == "scale" else [entry]:
+            if count not in counts:
+                counts.append(count)
+    return counts
+
 
 def main():
     parser = argparse.ArgumentParser(
@@ -108,8 +262,36 @@ def main():
             "keccak2561mb",
             "keccak2563mb",
             "keccak25610mb",
+            "blake2s100kb",
+            "blake2s1mb",
+            "blake2s10mb",
+            "blake3100kb",
+            "blake31mb",
+            "blake310mb",
+            "ripemd160100kb",
+            "ripemd1601mb",
+            "ripemd16010mb",
+            "poseidon2-babybear10kb",
+            "poseidon2-babybear100kb",
+            "poseidon2-babybear1mb",
+            "poseidon2-bn25410kb",
+            "poseidon2-bn254100kb",
+            "poseidon2-bn2541mb",
+            "mulmod256",
+            "mulmod256-baseline",
+            "modexp2048",
+            "modexp2048-baseline",
+            "modexp4096",
+            "modexp4096-baseline",
+            "bn254-pairing",
+            "bn254-pairing-baseline",
+            "bls12381-pairing",
+            "bls12381-pairing-baseline",
             "tendermint",
-            "ssz-withdrawals",
+            "tendermint-skipping",
+            "ssz-withdrawals1x",
+            "ssz-withdrawals10x",
+            "ssz-withdrawals100x",
             "rsp20526626",
             "rsp20526627",
             "rsp20526628",
@@ -122,13 +304,53 @@ def main():
             "rsp20528712",
             "ecdsa-verify",
             "eddsa-verify",
-            "helios",
+            "p256-verify",
+            "schnorr-verify",
+            "bls-aggregate-verify",
+            "helios0",
+            "helios1",
+            "helios4",
             "groth16-proof-verify",
-            "zk-email"
+            "zk-email",
+            "aggregation1",
+            "aggregation4",
+            "aggregation16",
         ],
         help="List of programs to benchmark",
         choices=[
             "loop10k",
+            "random-access10k",
+            "random-access100k",
+            "random-access1m",
+            "random-access3m",
+            "random-access10m",
+            "random-access30m",
+            "random-access100m",
+            "random-access300m",
+            "memcpy10k",
+            "memcpy100k",
+            "memcpy1m",
+            "memcpy3m",
+            "memcpy10m",
+            "memcpy30m",
+            "memcpy100m",
+            "memcpy300m",
+            "sort10k",
+            "sort100k",
+            "sort1m",
+            "sort3m",
+            "sort10m",
+            "sort30m",
+            "sort100m",
+            "sort300m",
+            "btreemap10k",
+            "btreemap100k",
+            "btreemap1m",
+            "btreemap3m",
+            "btreemap10m",
+            "btreemap30m",
+            "btreemap100m",
+            "btreemap300m",
             "loop100k",
             "loop1m",
             "loop3m",
@@ -157,8 +379,36 @@ def main():
             "keccak2561mb",
             "keccak2563mb",
             "keccak25610mb",
+            "blake2s100kb",
+            "blake2s1mb",
+            "blake2s10mb",
+            "blake3100kb",
+            "blake31mb",
+            "blake310mb",
+            "ripemd160100kb",
+            "ripemd1601mb",
+            "ripemd16010mb",
+            "poseidon2-babybear10kb",
+            "poseidon2-babybear100kb",
+            "poseidon2-babybear1mb",
+            "poseidon2-bn25410kb",
+            "poseidon2-bn254100kb",
+            "poseidon2-bn2541mb",
+            "mulmod256",
+            "mulmod256-baseline",
+            "modexp2048",
+            "modexp2048-baseline",
+            "modexp4096",
+            "modexp4096-baseline",
+            "bn254-pairing",
+            "bn254-pairing-baseline",
+            "bls12381-pairing",
+            "bls12381-pairing-baseline",
             "tendermint",
-            "ssz-withdrawals",
+            "tendermint-skipping",
+            "ssz-withdrawals1x",
+            "ssz-withdrawals10x",
+            "ssz-withdrawals100x",
             "rsp20526626",
             "rsp20526627",
             "rsp20526628",
@@ -171,9 +421,17 @@ def main():
             "rsp20528712",
             "ecdsa-verify",
             "eddsa-verify",
-            "helios",
+            "p256-verify",
+            "schnorr-verify",
+            "bls-aggregate-verify",
+            "helios0",
+            "helios1",
+            "helios4",
             "groth16-proof-verify",
-            "zk-email"
+            "zk-email",
+            "aggregation1",
+            "aggregation4",
+            "aggregation16",
         ],
     )
     parser.add_argument(
@@ -188,7 +446,7 @@ def main():
         nargs="+",
         default=["poseidon"],
         help="List of hash functions to use",
-        choices=["poseidon"],
+        choices=["poseidon", "sha256", "blake2b"],
     )
     parser.add_argument(
         "--shard-sizes",
@@ -197,6 +455,98 @@ def main():
         default=[21],
         help="List of shard sizes to use",
     )
+    parser.add_argument(
+        "--risc0-receipt-kinds",
+        nargs="+",
+        default=["succinct"],
+        help="List of RISC Zero receipt kinds to compress to",
+        choices=["composite", "succinct", "groth16"],
+    )
+    parser.add_argument(
+        "--risc0-max-segment-po2s",
+        type=int,
+        nargs="+",
+        default=[None],
+        help="List of RISC Zero max segment po2s to use",
+    )
+    parser.add_argument(
+        "--risc0-accelerators",
+        nargs="+",
+        default=["bigint2"],
+        help="List of RISC Zero guest accelerator sets to build with (e.g. 'bigint2,keccak' or 'none')",
+    )
+    parser.add_argument(
+        "--hash-message-sizes",
+        type=int,
+        nargs="+",
+        default=[None],
+        help="List of message sizes in bytes the hashing programs hash separately (default: one message)",
+    )
+    parser.add_argument(
+        "--hash-bytes",
+        nargs="+",
+        default=["zero"],
+        help="List of bytes the hashing programs hash",
+        choices=["zero", "random"],
+    )
+    parser.add_argument(
+        "--seed",
+        type=int,
+        default=0,
+        help="Seed of the randomly generated guest inputs",
+    )
+    parser.add_argument(
+        "--precompiles",
+        default="on",
+        help="Whether to prove the guests with their accelerated crates, without them, or both",
+        choices=["on", "off", "ab"],
+    )
+    parser.add_argument(
+        "--guest-opt-levels",
+        nargs="+",
+        default=["3"],
+        help="List of optimization levels to build the guests with",
+        choices=["2", "3", "s", "z"],
+    )
+    parser.add_argument(
+        "--guest-ltos",
+        nargs="+",
+        default=[None],
+        help="List of link-time optimizations to build the guests with (default: the guest's manifest)",
+        choices=["off", "thin", "fat"],
+    )
+    parser.add_argument(
+        "--guest-codegen-units",
+        type=int,
+        nargs="+",
+        default=[16],
+        help="List of codegen units to build the guests with",
+    )
+    parser.add_argument(
+        "--guest-panics",
+        nargs="+",
+        default=["abort"],
+        help="List of panic strategies to build the guests with",
+        choices=["abort", "unwind"],
+    )
+    parser.add_argument(
+        "--threads",
+        nargs="+",
+        type=thread_count,
+        default=[None],
+        help="List of thread counts to prove with, or 'scale' for 1, 2, 4, ... up to every hardware "
+        "thread. Reports the scaling efficiency when given more than one (default: every thread)",
+    )
+    parser.add_argument(
+        "--timeout",
+        type=int,
+        help="Seconds after which a run is killed and recorded as a timeout",
+    )
+    parser.add_argument(
+        "--max-rss",
+        type=int,
+        help="Resident memory in MiB past which a run is killed and recorded as out of memory",
+    )
     parser.add_argument("--block-1", default="17106222", help="Block number for reth1")
     parser.add_argument("--block-2", default="19409768", help="Block number for reth2")
 
@@ -211,6 +561,20 @@ def main():
         args.shard_sizes,
         args.block_1,
         args.block_2,
+        args.risc0_receipt_kinds,
+        args.risc0_max_segment_po2s,
+        args.risc0_accelerators,
+        args.hash_message_sizes,
+        args.hash_bytes,
+        args.seed,
+        args.precompiles,
+        args.guest_opt_levels,
+        args.guest_ltos,
+        args.guest_codegen_units,
+        args.guest_panics,
+        thread_counts(args.threads),
+        args.timeout,
+        args.max_rss,
     )
 
 